@@ -0,0 +1,377 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use rust_decimal::Decimal;
+
+use crate::{FromReflectedString, Type, Value};
+
+/// String representation of `Value` for a specific consumer
+///
+/// Used with `Reflected::get_value_with` and `Reflected::set_value_with`.
+/// Field type is passed to `decode` so `1` can become an int, a float or a
+/// bool.
+pub trait ReflectedCodec {
+    fn encode(&self, value: &Value) -> String;
+    fn decode(&self, tp: &Type, value: &str) -> Result<Value, String>;
+}
+
+/// SQL literals: `NULL`, quoted text and dates, `1`/`0` bools
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SqlCodec;
+
+/// JSON literals: `null`, `true`/`false`, quoted text, ISO-8601 dates and
+/// decimals as strings to keep precision
+#[derive(Debug, Default, Clone, Copy)]
+pub struct JsonCodec;
+
+/// For UI: empty string for none, `yes`/`no` bools and dates without fractions
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HumanCodec;
+
+/// Default format for output, accepts common alternate spellings on input:
+/// - `true`/`yes`/`on` bools
+/// - `42.0` or `+42` integers
+/// - `1,5` floats and decimals, `1,000` is rejected as ambiguous
+/// - date only, ISO-8601 and RFC 3339 dates
+/// - `null` or empty string for optional fields
+/// - case insensitive enum variants when used with
+///   `Reflected::set_value_lenient`
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LenientCodec;
+
+const SQL_DATE_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.f";
+const ISO_DATE_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+const HUMAN_DATE_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+impl ReflectedCodec for SqlCodec {
+    fn encode(&self, value: &Value) -> String {
+        match value {
+            Value::Null => "NULL".to_string(),
+            Value::Bool(value) => if *value { "1" } else { "0" }.to_string(),
+            Value::Float(value) => value.to_string(),
+            Value::Text(value) => sql_quote(value),
+            Value::Date(value) => sql_quote(&value.format(SQL_DATE_FORMAT).to_string()),
+            value => value.to_string(),
+        }
+    }
+
+    fn decode(&self, tp: &Type, value: &str) -> Result<Value, String> {
+        if value.eq_ignore_ascii_case("NULL") {
+            return Ok(Value::Null);
+        }
+
+        let value = sql_unquote(value)?;
+
+        decode(
+            *tp,
+            &value,
+            |value| match value.to_ascii_lowercase().as_str() {
+                "1" | "true" => Ok(true),
+                "0" | "false" => Ok(false),
+                _ => Err("expected `0` or `1`".to_string()),
+            },
+            |value| NaiveDateTime::parse_from_str(value, SQL_DATE_FORMAT).map_err(|e| e.to_string()),
+        )
+    }
+}
+
+impl ReflectedCodec for JsonCodec {
+    fn encode(&self, value: &Value) -> String {
+        match value {
+            Value::Bool(value) => value.to_string(),
+            Value::Float(value) if value.is_finite() => value.to_string(),
+            Value::Null | Value::Float(_) => "null".to_string(),
+            Value::Text(value) => json_quote(value),
+            Value::Date(value) => json_quote(&value.format(ISO_DATE_FORMAT).to_string()),
+            Value::Decimal(value) => json_quote(&value.to_string()),
+            value => value.to_string(),
+        }
+    }
+
+    fn decode(&self, tp: &Type, value: &str) -> Result<Value, String> {
+        let value = value.trim();
+
+        if value == "null" {
+            return Ok(Value::Null);
+        }
+
+        let value = if value.starts_with('"') {
+            json_unquote(value)?
+        } else if tp.is_text() || tp.is_enum() || tp.is_date() {
+            return Err(format!("expected JSON string, got `{value}`"));
+        } else {
+            value.to_string()
+        };
+
+        decode(
+            *tp,
+            &value,
+            |value| match value {
+                "true" => Ok(true),
+                "false" => Ok(false),
+                _ => Err("expected `true` or `false`".to_string()),
+            },
+            |value| {
+                NaiveDateTime::parse_from_str(value.trim_end_matches('Z'), ISO_DATE_FORMAT)
+                    .map_err(|e| e.to_string())
+            },
+        )
+    }
+}
+
+impl ReflectedCodec for HumanCodec {
+    fn encode(&self, value: &Value) -> String {
+        match value {
+            Value::Null => String::new(),
+            Value::Bool(value) => if *value { "yes" } else { "no" }.to_string(),
+            Value::Float(value) => value.to_string(),
+            Value::Date(value) => value.format(HUMAN_DATE_FORMAT).to_string(),
+            value => value.to_string(),
+        }
+    }
+
+    fn decode(&self, tp: &Type, value: &str) -> Result<Value, String> {
+        if value.trim().is_empty() && (tp.is_optional() || !tp.is_text()) {
+            return Ok(Value::Null);
+        }
+
+        let value = if tp.is_text() { value } else { value.trim() };
+
+        decode(*tp, value, lenient_bool, |value| {
+            lenient_date(value).map_err(|_| format!("expected date in `{HUMAN_DATE_FORMAT}` format"))
+        })
+    }
+}
+
+impl ReflectedCodec for LenientCodec {
+    fn encode(&self, value: &Value) -> String {
+        value.to_string()
+    }
+
+    fn decode(&self, tp: &Type, value: &str) -> Result<Value, String> {
+        let trimmed = value.trim();
+
+        if tp.is_text() {
+            return Ok(if tp.is_optional() && trimmed.eq_ignore_ascii_case("NULL") {
+                Value::Null
+            } else {
+                Value::Text(value.to_string())
+            });
+        }
+
+        if trimmed.is_empty() || trimmed.eq_ignore_ascii_case("NULL") {
+            return Ok(Value::Null);
+        }
+
+        let mut number = trimmed.strip_prefix('+').unwrap_or(trimmed).replace('_', "");
+
+        if tp.is_integer() {
+            if let Some((int, fraction)) = number.split_once('.') {
+                if fraction.chars().all(|a| a == '0') {
+                    number = int.to_string();
+                }
+            }
+        } else if (tp.is_float() || tp.is_decimal()) && !number.contains('.') {
+            if let Some((_, fraction)) = number.split_once(',') {
+                // `1,000` could be a thousand or one
+                if fraction.len() == 3 || fraction.contains(',') {
+                    return Err("ambiguous `,`, use `.` as decimal separator".to_string());
+                }
+                number = number.replacen(',', ".", 1);
+            }
+        }
+
+        let value = if tp.is_number() || tp.is_decimal() {
+            &number
+        } else {
+            trimmed
+        };
+
+        decode(*tp, value, lenient_bool, lenient_date)
+    }
+}
+
+fn lenient_bool(value: &str) -> Result<bool, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "yes" | "y" | "true" | "t" | "on" | "1" => Ok(true),
+        "no" | "n" | "false" | "f" | "off" | "0" => Ok(false),
+        _ => Err("expected `yes` or `no`".to_string()),
+    }
+}
+
+/// RFC 3339 dates with offset are converted to UTC
+pub(crate) fn lenient_date(value: &str) -> Result<NaiveDateTime, String> {
+    const FORMATS: &[&str] = &[
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M",
+    ];
+    const DATE_FORMATS: &[&str] = &["%Y-%m-%d", "%Y/%m/%d", "%d.%m.%Y"];
+
+    DateTime::parse_from_rfc3339(value)
+        .map(|date| date.naive_utc())
+        .ok()
+        .or_else(|| {
+            FORMATS
+                .iter()
+                .find_map(|format| NaiveDateTime::parse_from_str(value.trim_end_matches('Z'), format).ok())
+        })
+        .or_else(|| {
+            DATE_FORMATS
+                .iter()
+                .find_map(|format| NaiveDate::parse_from_str(value, format).ok()?.and_hms_opt(0, 0, 0))
+        })
+        .ok_or_else(|| format!("unsupported date format: {value}"))
+}
+
+/// Parses unquoted value according to field type
+fn decode(
+    tp: Type,
+    value: &str,
+    bool: impl Fn(&str) -> Result<bool, String>,
+    date: impl Fn(&str) -> Result<NaiveDateTime, String>,
+) -> Result<Value, String> {
+    Ok(if tp.is_integer() {
+        match i128::from_reflected_string(value) {
+            Ok(int) => Value::Int(int),
+            Err(error) => Value::UInt(u128::from_reflected_string(value).map_err(|_| error)?),
+        }
+    } else if tp.is_float() {
+        Value::Float(f64::from_reflected_string(value)?)
+    } else if tp.is_decimal() {
+        Value::Decimal(Decimal::from_reflected_string(value)?)
+    } else if tp.is_bool() {
+        Value::Bool(bool(value)?)
+    } else if tp.is_date() {
+        Value::Date(date(value)?)
+    } else {
+        Value::Text(value.to_string())
+    })
+}
+
+fn sql_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+fn sql_unquote(value: &str) -> Result<String, String> {
+    if !value.starts_with('\'') {
+        return Ok(value.to_string());
+    }
+
+    if value.len() < 2 || !value.ends_with('\'') {
+        return Err(format!("unterminated SQL string: {value}"));
+    }
+
+    Ok(value[1..value.len() - 1].replace("''", "'"))
+}
+
+fn json_quote(value: &str) -> String {
+    let mut res = String::with_capacity(value.len() + 2);
+    res.push('"');
+
+    for ch in value.chars() {
+        match ch {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\r' => res.push_str("\\r"),
+            '\t' => res.push_str("\\t"),
+            ch if ch.is_control() => res.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => res.push(ch),
+        }
+    }
+
+    res.push('"');
+    res
+}
+
+/// `value` must include the surrounding quotes
+fn json_unquote(value: &str) -> Result<String, String> {
+    let Some(inner) = value.strip_prefix('"').and_then(|a| a.strip_suffix('"')) else {
+        return Err(format!("unterminated JSON string: {value}"));
+    };
+
+    let mut res = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            res.push(ch);
+            continue;
+        }
+
+        match chars.next() {
+            Some('"') => res.push('"'),
+            Some('\\') => res.push('\\'),
+            Some('/') => res.push('/'),
+            Some('b') => res.push('\u{8}'),
+            Some('f') => res.push('\u{c}'),
+            Some('n') => res.push('\n'),
+            Some('r') => res.push('\r'),
+            Some('t') => res.push('\t'),
+            Some('u') => {
+                let mut code = code_unit(&mut chars)?;
+
+                if (0xD800..0xDC00).contains(&code) {
+                    if chars.next() != Some('\\') || chars.next() != Some('u') {
+                        return Err("unpaired surrogate in JSON string".to_string());
+                    }
+                    let low = code_unit(&mut chars)?;
+                    if !(0xDC00..0xE000).contains(&low) {
+                        return Err("unpaired surrogate in JSON string".to_string());
+                    }
+                    code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                }
+
+                res.push(char::from_u32(code).ok_or_else(|| format!("invalid unicode escape: {code:x}"))?);
+            }
+            ch => return Err(format!("invalid JSON escape: \\{}", ch.unwrap_or_default())),
+        }
+    }
+
+    Ok(res)
+}
+
+/// Exactly 4 hex digits, `from_str_radix` alone would accept a sign
+fn code_unit(chars: &mut std::str::Chars) -> Result<u32, String> {
+    let hex: String = chars.take(4).collect();
+
+    if hex.len() != 4 || !hex.chars().all(|a| a.is_ascii_hexdigit()) {
+        return Err(format!("invalid unicode escape: \\u{hex}"));
+    }
+
+    u32::from_str_radix(&hex, 16).map_err(|_| format!("invalid unicode escape: \\u{hex}"))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::codec::{json_quote, json_unquote};
+
+    #[test]
+    fn escapes() {
+        assert_eq!(
+            json_unquote(r#""a\"\\\/\b\f\n\r\t\u0041""#),
+            Ok("a\"\\/\u{8}\u{c}\n\r\tA".to_string())
+        );
+
+        let text = "quote \" slash \\ line \n tab \t bell \u{7} é";
+        assert_eq!(json_unquote(&json_quote(text)).as_deref(), Ok(text));
+        assert_eq!(json_quote("\u{7}"), r#""\u0007""#);
+
+        assert!(json_unquote(r#""\x""#).is_err());
+        assert!(json_unquote(r#""\u12""#).is_err());
+        assert!(json_unquote(r#""\u+123""#).is_err());
+        assert!(json_unquote(r#""abc"#).is_err());
+    }
+
+    #[test]
+    fn surrogate_pairs() {
+        assert_eq!(json_unquote(r#""\ud83d\ude00""#).as_deref(), Ok("😀"));
+        assert_eq!(json_unquote(r#""\uD834\uDD1E""#).as_deref(), Ok("𝄞"));
+
+        assert!(json_unquote(r#""\ud83d""#).is_err());
+        assert!(json_unquote(r#""\ud83dx""#).is_err());
+        assert!(json_unquote(r#""\ud83d\u0041""#).is_err());
+        assert!(json_unquote(r#""\ud83d\ud83d""#).is_err());
+        assert!(json_unquote(r#""\ude00""#).is_err());
+    }
+}
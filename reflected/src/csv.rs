@@ -0,0 +1,368 @@
+//! Streaming CSV reader and writer
+//!
+//! Columns are all fields except nested structs and custom fields, header
+//! contains field names. Values are written in `Reflected::get_value` format
+//! and parsed with `Reflected::set_value`.
+
+use std::{
+    fmt::{Display, Formatter},
+    io::{self, BufRead, BufReader, Read, Write},
+    marker::PhantomData,
+    mem::take,
+};
+
+use crate::{Field, Reflected};
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// Malformed row or header
+    Syntax {
+        row:    usize,
+        column: usize,
+        error:  String,
+    },
+    /// Cell can't be set to the field
+    Reflected {
+        row:    usize,
+        column: usize,
+        error:  crate::Error,
+    },
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "IO error: {error}"),
+            Self::Syntax { row, column, error } => {
+                write!(f, "CSV error at row {row}, column {column}: {error}")
+            }
+            Self::Reflected { row, column, error } => {
+                write!(f, "CSV error at row {row}, column {column}: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Format {
+    pub delimiter: char,
+    /// Unquoted cell with this text is null, empty string by default.
+    /// Non optional fields take it as is.
+    pub null:      String,
+}
+
+impl Default for Format {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            null:      String::new(),
+        }
+    }
+}
+
+/// Header is written before the first row or on `flush`
+pub struct Writer<W: Write, T> {
+    writer:         W,
+    format:         Format,
+    header_written: bool,
+    _type:          PhantomData<T>,
+}
+
+impl<W: Write, T: Reflected> Writer<W, T> {
+    pub fn new(writer: W) -> Self {
+        Self::with_format(writer, Format::default())
+    }
+
+    pub fn with_format(writer: W, format: Format) -> Self {
+        Self {
+            writer,
+            format,
+            header_written: false,
+            _type: PhantomData,
+        }
+    }
+
+    pub fn write(&mut self, row: &T) -> Result<()> {
+        self.write_header()?;
+
+        let cells: Vec<_> = T::column_fields()
+            .into_iter()
+            .map(|field| {
+                let value = row.get_val(field);
+                if value.is_null() {
+                    self.format.null.clone()
+                } else {
+                    self.quote(&value.to_string())
+                }
+            })
+            .collect();
+
+        self.write_line(&cells)
+    }
+
+    pub fn write_all<'a>(&mut self, rows: impl IntoIterator<Item = &'a T>) -> Result<()>
+    where T: 'a {
+        for row in rows {
+            self.write(row)?;
+        }
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<()> {
+        self.write_header()?;
+        Ok(self.writer.flush()?)
+    }
+
+    pub fn into_inner(mut self) -> Result<W> {
+        self.flush()?;
+        Ok(self.writer)
+    }
+
+    fn write_header(&mut self) -> Result<()> {
+        if self.header_written {
+            return Ok(());
+        }
+        self.header_written = true;
+
+        let header: Vec<_> = T::column_fields().into_iter().map(|field| self.quote(field.name)).collect();
+        self.write_line(&header)
+    }
+
+    fn write_line(&mut self, cells: &[String]) -> Result<()> {
+        let line = cells.join(&self.format.delimiter.to_string());
+        Ok(writeln!(self.writer, "{line}")?)
+    }
+
+    /// Text equal to null token is quoted so it is read back as text
+    fn quote(&self, text: &str) -> String {
+        if text == self.format.null || text.contains([self.format.delimiter, '"', '\n', '\r']) {
+            format!("\"{}\"", text.replace('"', "\"\""))
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+/// Iterator over rows, first row is a header with field names.
+/// Columns missing in header keep default values. Rows are numbered from 1
+/// including header, columns are numbered from 1.
+pub struct Reader<R, T: 'static> {
+    reader: BufReader<R>,
+    format: Format,
+    header: Option<Vec<&'static Field<'static, T>>>,
+    row:    usize,
+}
+
+#[derive(Default)]
+struct Cell {
+    text:   String,
+    quoted: bool,
+}
+
+impl<R: Read, T: Reflected> Reader<R, T> {
+    pub fn new(reader: R) -> Self {
+        Self::with_format(reader, Format::default())
+    }
+
+    pub fn with_format(reader: R, format: Format) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            format,
+            header: None,
+            row: 0,
+        }
+    }
+
+    fn read_row(&mut self) -> Result<Option<T>> {
+        if self.header.is_none() {
+            let Some(header) = self.read_header()? else {
+                return Ok(None);
+            };
+            self.header = Some(header);
+        }
+
+        let Some(cells) = self.read_record()? else {
+            return Ok(None);
+        };
+
+        let header = self.header.as_ref().unwrap();
+        let row = self.row;
+
+        if cells.len() != header.len() {
+            return Err(Error::Syntax {
+                row,
+                column: cells.len().min(header.len()) + 1,
+                error: format!("expected {} columns, got {}", header.len(), cells.len()),
+            });
+        }
+
+        let mut value = T::default();
+
+        for (index, (field, cell)) in header.iter().zip(&cells).enumerate() {
+            let is_null = !cell.quoted && cell.text == self.format.null && field.is_optional();
+            value
+                .try_set_value(field, (!is_null).then_some(cell.text.as_str()))
+                .map_err(|error| Error::Reflected {
+                    row,
+                    column: index + 1,
+                    error,
+                })?;
+        }
+
+        Ok(Some(value))
+    }
+
+    fn read_header(&mut self) -> Result<Option<Vec<&'static Field<'static, T>>>> {
+        let Some(cells) = self.read_record()? else {
+            return Ok(None);
+        };
+
+        let mut header: Vec<&'static Field<'static, T>> = vec![];
+
+        for (index, cell) in cells.iter().enumerate() {
+            let row = self.row;
+            let column = index + 1;
+
+            let name = cell.text.trim_start_matches('\u{feff}');
+
+            let field =
+                T::try_field_by_name(name).map_err(|error| Error::Reflected { row, column, error })?;
+
+            let error = if field.is_struct() {
+                crate::Error::NestedStruct { field: field.name }
+            } else if field.is_custom() {
+                crate::Error::UnsupportedCustom { field: field.name }
+            } else if header.iter().any(|a| a.name == field.name) {
+                return Err(Error::Syntax {
+                    row,
+                    column,
+                    error: format!("duplicate column `{name}`"),
+                });
+            } else {
+                header.push(field);
+                continue;
+            };
+
+            return Err(Error::Reflected { row, column, error });
+        }
+
+        Ok(Some(header))
+    }
+
+    /// Reads lines until quoted values are closed, skips empty lines
+    fn read_record(&mut self) -> Result<Option<Vec<Cell>>> {
+        let mut line = String::new();
+
+        loop {
+            line.clear();
+            if self.reader.read_line(&mut line)? == 0 {
+                return Ok(None);
+            }
+            if !line.trim_end_matches(['\r', '\n']).is_empty() {
+                break;
+            }
+        }
+
+        self.row += 1;
+
+        let row = self.row;
+        let syntax = |column: usize, error: &str| Error::Syntax {
+            row,
+            column,
+            error: error.to_string(),
+        };
+        let is_line_end = |chars: &[char], i: usize| match chars.get(i) {
+            None | Some('\n') => true,
+            Some('\r') => matches!(chars.get(i + 1), None | Some('\n')),
+            _ => false,
+        };
+
+        let delimiter = self.format.delimiter;
+        let mut chars: Vec<char> = line.chars().collect();
+        let mut i = 0;
+        let mut cells = vec![];
+        let mut cell = Cell::default();
+
+        loop {
+            let column = cells.len() + 1;
+
+            if chars.get(i) == Some(&'"') {
+                cell.quoted = true;
+                i += 1;
+
+                loop {
+                    match chars.get(i) {
+                        None => {
+                            line.clear();
+                            if self.reader.read_line(&mut line)? == 0 {
+                                return Err(syntax(column, "unterminated quoted value"));
+                            }
+                            chars.extend(line.chars());
+                        }
+                        Some('"') if chars.get(i + 1) == Some(&'"') => {
+                            cell.text.push('"');
+                            i += 2;
+                        }
+                        Some('"') => {
+                            i += 1;
+                            break;
+                        }
+                        Some(char) => {
+                            cell.text.push(*char);
+                            i += 1;
+                        }
+                    }
+                }
+            } else {
+                while !is_line_end(&chars, i) && chars[i] != delimiter {
+                    if chars[i] == '"' {
+                        return Err(syntax(column, "unexpected quote in unquoted value"));
+                    }
+                    cell.text.push(chars[i]);
+                    i += 1;
+                }
+            }
+
+            cells.push(take(&mut cell));
+
+            if is_line_end(&chars, i) {
+                return Ok(Some(cells));
+            }
+
+            if chars[i] != delimiter {
+                return Err(syntax(column, "expected delimiter after closing quote"));
+            }
+
+            i += 1;
+        }
+    }
+}
+
+impl<R: Read, T: Reflected> Iterator for Reader<R, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Result<T>> {
+        self.read_row().transpose()
+    }
+}
+
+pub fn to_string<T: Reflected>(rows: &[T]) -> String {
+    let mut writer = Writer::new(vec![]);
+    writer.write_all(rows).expect("Writing to Vec doesn't fail");
+    String::from_utf8(writer.into_inner().expect("Writing to Vec doesn't fail")).unwrap()
+}
+
+pub fn from_str<T: Reflected>(csv: &str) -> Result<Vec<T>> {
+    Reader::new(csv.as_bytes()).collect()
+}
@@ -0,0 +1,93 @@
+use std::any::Any;
+
+use rand::RngCore;
+
+use crate::{schema::Definition, Error, Reflected, ReflectedEq, Type, Value};
+
+/// Object safe part of `Reflected` used to access nested structs
+pub trait DynReflected: Any {
+    fn dyn_type_name(&self) -> &'static str;
+    fn dyn_field_names(&self) -> Vec<&'static str>;
+    fn dyn_fields(&self) -> Vec<(&'static str, Type)>;
+    fn dyn_definition(&self) -> Definition;
+
+    fn dyn_get_val(&self, name: &str) -> Result<Value, Error>;
+    fn dyn_set_val(&mut self, name: &str, value: Value) -> Result<(), Error>;
+
+    fn dyn_nested(&self, name: &str) -> Result<Option<&dyn DynReflected>, Error>;
+    fn dyn_nested_mut(&mut self, name: &str) -> Result<&mut dyn DynReflected, Error>;
+
+    fn dyn_value_by_path(&self, path: &str) -> Result<String, Error>;
+    fn dyn_set_value_by_path(&mut self, path: &str, value: Option<&str>) -> Result<(), Error>;
+
+    fn dyn_randomize(&mut self);
+    fn dyn_randomize_with(&mut self, rng: &mut dyn RngCore);
+    fn dyn_assert_eq(&self, other: &dyn DynReflected);
+
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: Reflected> DynReflected for T {
+    fn dyn_type_name(&self) -> &'static str {
+        T::type_name()
+    }
+
+    fn dyn_field_names(&self) -> Vec<&'static str> {
+        T::fields().iter().map(|a| a.name).collect()
+    }
+
+    fn dyn_fields(&self) -> Vec<(&'static str, Type)> {
+        T::fields().iter().map(|a| (a.name, a.tp)).collect()
+    }
+
+    fn dyn_definition(&self) -> Definition {
+        Definition::of::<T>()
+    }
+
+    fn dyn_get_val(&self, name: &str) -> Result<Value, Error> {
+        self.try_get_val(T::try_field_by_name(name)?)
+    }
+
+    fn dyn_set_val(&mut self, name: &str, value: Value) -> Result<(), Error> {
+        self.try_set_val(T::try_field_by_name(name)?, value)
+    }
+
+    fn dyn_nested(&self, name: &str) -> Result<Option<&dyn DynReflected>, Error> {
+        self.nested(T::try_field_by_name(name)?)
+    }
+
+    fn dyn_nested_mut(&mut self, name: &str) -> Result<&mut dyn DynReflected, Error> {
+        self.nested_mut(T::try_field_by_name(name)?)
+    }
+
+    fn dyn_value_by_path(&self, path: &str) -> Result<String, Error> {
+        self.try_value_by_path(path)
+    }
+
+    fn dyn_set_value_by_path(&mut self, path: &str, value: Option<&str>) -> Result<(), Error> {
+        self.try_set_value_by_path(path, value)
+    }
+
+    fn dyn_randomize(&mut self) {
+        *self = T::random();
+    }
+
+    fn dyn_randomize_with(&mut self, rng: &mut dyn RngCore) {
+        *self = T::random_with(rng);
+    }
+
+    fn dyn_assert_eq(&self, other: &dyn DynReflected) {
+        let Some(other) = other.as_any().downcast_ref::<T>() else {
+            panic!(
+                "Reflected eq error: {} compared with {}",
+                self.dyn_type_name(),
+                other.dyn_type_name()
+            );
+        };
+        self.assert_eq(other);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
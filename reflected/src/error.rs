@@ -0,0 +1,83 @@
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    UnknownField {
+        type_name: &'static str,
+        name:      String,
+    },
+    Parse {
+        field: &'static str,
+        value: String,
+        error: String,
+    },
+    NullNotOptional {
+        field: &'static str,
+    },
+    UnsupportedCustom {
+        field: &'static str,
+    },
+    NestedStruct {
+        field: &'static str,
+    },
+    NotStruct {
+        field: &'static str,
+    },
+    NoPrimaryKey {
+        type_name: &'static str,
+    },
+    /// Every column is a part of the primary key
+    NothingToUpdate {
+        type_name: &'static str,
+    },
+    EmptyBatch,
+    /// Several rows without insertable columns, only `Dialect::MySql` can
+    /// insert them in one statement
+    NothingToInsert {
+        type_name: &'static str,
+    },
+    InvalidSnapshot {
+        error: String,
+    },
+}
+
+impl Error {
+    pub fn parse(field: &'static str, value: impl ToString, error: impl ToString) -> Self {
+        Self::Parse {
+            field,
+            value: value.to_string(),
+            error: error.to_string(),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownField { type_name, name } => write!(f, "Unknown field `{name}` in `{type_name}`"),
+            Self::Parse { field, value, error } => {
+                write!(f, "Failed to parse `{field}` from {value:?}: {error}")
+            }
+            Self::NullNotOptional { field } => write!(f, "NULL value for non optional field `{field}`"),
+            Self::UnsupportedCustom { field } => write!(f, "Custom field `{field}` is not supported"),
+            Self::NestedStruct { field } => {
+                write!(f, "Field `{field}` is a nested struct, access its fields by path")
+            }
+            Self::NotStruct { field } => write!(f, "Field `{field}` is not a nested struct"),
+            Self::NoPrimaryKey { type_name } => write!(f, "`{type_name}` has no primary key"),
+            Self::NothingToUpdate { type_name } => {
+                write!(
+                    f,
+                    "`{type_name}` has no columns to update besides the primary key"
+                )
+            }
+            Self::EmptyBatch => write!(f, "Batch is empty"),
+            Self::NothingToInsert { type_name } => {
+                write!(f, "`{type_name}` has no columns to insert several rows at once")
+            }
+            Self::InvalidSnapshot { error } => write!(f, "Invalid schema snapshot: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
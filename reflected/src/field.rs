@@ -6,30 +6,52 @@ use std::{
     ops::Deref,
 };
 
-use crate::Type;
+use crate::{Reference, Type};
 
 pub type FieldRef<T> = &'static Field<'static, T>;
 
+/// Flags are plain fields so generated `FIELDS` consts can build it
+#[allow(clippy::struct_excessive_bools)]
 pub struct Field<'a, T> {
-    pub name:        &'a str,
-    pub tp:          Type,
-    pub type_name:   &'a str,
-    pub parent_name: &'a str,
-    pub optional:    bool,
-    pub _p:          PhantomData<T>,
+    pub name:          &'a str,
+    pub tp:            Type,
+    pub type_name:     &'a str,
+    /// Type set with `#[reflected(type = "u64")]`, `type_name` stays the alias
+    pub type_override: Option<&'a str>,
+    pub parent_name:   &'a str,
+    pub optional:      bool,
+    /// Variant names when field is a `Type::Enum`
+    pub variants:      &'a [&'a str],
+    pub primary_key:   bool,
+    pub foreign_key:   bool,
+    /// Struct this foreign key points to if specified with
+    /// `#[reflected(foreign_key = "Type")]`
+    pub references:    Option<Reference>,
+    /// Set with `#[reflected(lenient)]`, `set_value` parses like
+    /// `set_value_lenient`
+    pub lenient:       bool,
+    /// Field number in `.proto`, declaration order unless set with
+    /// `#[reflected(tag = 3)]`
+    pub tag:           u32,
+    pub _p:            PhantomData<T>,
 }
 
 impl<T> Field<'_, T> {
     pub fn is_id(&self) -> bool {
-        self.name == "id"
+        self.primary_key
     }
 
     pub fn is_foreign_id(&self) -> bool {
-        self.name.contains("_id")
+        self.foreign_key
     }
 
     pub fn is_simple(&self) -> bool {
-        !self.is_id() && !self.is_custom() && !self.is_foreign_id()
+        !self.is_id() && !self.is_custom() && !self.is_struct() && !self.is_foreign_id()
+    }
+
+    /// `type_override` if set, `type_name` otherwise
+    pub fn value_type_name(&self) -> &str {
+        self.type_override.unwrap_or(self.type_name)
     }
 }
 
@@ -82,12 +104,19 @@ mod test {
     #[test]
     fn store_in_map() {
         let field: &'static Field<Strekta> = &Field {
-            name:        "",
-            tp:          Type::Float,
-            type_name:   "",
-            parent_name: "",
-            optional:    false,
-            _p:          PhantomData,
+            name:          "",
+            tp:            Type::Float,
+            type_name:     "",
+            type_override: None,
+            parent_name:   "",
+            optional:      false,
+            variants:      &[],
+            primary_key:   false,
+            foreign_key:   false,
+            references:    None,
+            lenient:       false,
+            tag:           1,
+            _p:            PhantomData,
         };
 
         let mut map = HashMap::<&'static Field<Strekta>, String>::default();
@@ -97,12 +126,19 @@ mod test {
     #[test]
     fn debug() {
         let field: &'static Field<Strekta> = &Field {
-            name:        "Name",
-            tp:          Type::Float,
-            type_name:   "f32",
-            parent_name: "SomeStruct",
-            optional:    false,
-            _p:          PhantomData,
+            name:          "Name",
+            tp:            Type::Float,
+            type_name:     "f32",
+            type_override: None,
+            parent_name:   "SomeStruct",
+            optional:      false,
+            variants:      &[],
+            primary_key:   false,
+            foreign_key:   false,
+            references:    None,
+            lenient:       false,
+            tag:           1,
+            _p:            PhantomData,
         };
 
         dbg!(field);
@@ -1,4 +1,6 @@
-#[derive(Debug, PartialEq, Eq, Hash)]
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum OptionalType {
     Float,
     Integer,
@@ -6,6 +8,8 @@ pub enum OptionalType {
     Date,
     Decimal,
     Bool,
+    Enum,
+    Struct,
 }
 
 impl OptionalType {
@@ -17,6 +21,8 @@ impl OptionalType {
             Type::Date => OptionalType::Date,
             Type::Decimal => OptionalType::Decimal,
             Type::Bool => OptionalType::Bool,
+            Type::Enum => OptionalType::Enum,
+            Type::Struct => OptionalType::Struct,
             _ => unreachable!(),
         }
     }
@@ -29,11 +35,13 @@ impl OptionalType {
             OptionalType::Date => Type::Date,
             OptionalType::Decimal => Type::Decimal,
             OptionalType::Bool => Type::Bool,
+            OptionalType::Enum => Type::Enum,
+            OptionalType::Struct => Type::Struct,
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Type {
     Float,
     Integer,
@@ -41,6 +49,8 @@ pub enum Type {
     Date,
     Decimal,
     Bool,
+    Enum,
+    Struct,
     Custom,
     Optional(OptionalType),
 }
@@ -88,6 +98,14 @@ impl Type {
         self.is_type(Self::Bool)
     }
 
+    pub fn is_enum(&self) -> bool {
+        self.is_type(Self::Enum)
+    }
+
+    pub fn is_struct(&self) -> bool {
+        self.is_type(Self::Struct)
+    }
+
     pub fn is_custom(&self) -> bool {
         self.is_type(Self::Custom)
     }
@@ -99,6 +117,42 @@ impl Type {
     pub fn is_number(&self) -> bool {
         self.is_integer() || self.is_float()
     }
+
+    /// Variant name, `Optional` is named after the wrapped type
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Float => "Float",
+            Self::Integer => "Integer",
+            Self::Text => "Text",
+            Self::Date => "Date",
+            Self::Decimal => "Decimal",
+            Self::Bool => "Bool",
+            Self::Enum => "Enum",
+            Self::Struct => "Struct",
+            Self::Custom => "Custom",
+            Self::Optional(tp) => tp.to_type().as_str(),
+        }
+    }
+}
+
+/// Parses names returned by `Type::as_str`, never returns `Type::Optional`
+impl FromStr for Type {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "Float" => Self::Float,
+            "Integer" => Self::Integer,
+            "Text" => Self::Text,
+            "Date" => Self::Date,
+            "Decimal" => Self::Decimal,
+            "Bool" => Self::Bool,
+            "Enum" => Self::Enum,
+            "Struct" => Self::Struct,
+            "Custom" => Self::Custom,
+            _ => return Err(format!("unknown type `{value}`")),
+        })
+    }
 }
 
 #[cfg(test)]
@@ -109,5 +163,16 @@ mod test {
     fn test() {
         assert!(Type::Float.is_float());
         assert!(Type::Float.to_optional().is_float());
+        assert!(Type::Enum.to_optional().is_enum());
+    }
+
+    #[test]
+    fn names() {
+        for tp in [Type::Float, Type::Text, Type::Enum, Type::Custom] {
+            assert_eq!(tp.as_str().parse(), Ok(tp));
+        }
+
+        assert_eq!(Type::Date.to_optional().as_str(), "Date");
+        assert!("Optional".parse::<Type>().is_err());
     }
 }
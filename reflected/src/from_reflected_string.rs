@@ -0,0 +1,49 @@
+use std::str::FromStr;
+
+use chrono::NaiveDateTime;
+use rust_decimal::Decimal;
+
+use crate::ReflectedEnum;
+
+/// Inverse of `ToReflectedString`
+pub trait FromReflectedString: Sized {
+    fn from_reflected_string(value: &str) -> Result<Self, String>;
+}
+
+macro_rules! from_str_reflected_string {
+    ($($tp:ty),*) => {
+        $(
+            impl FromReflectedString for $tp {
+                fn from_reflected_string(value: &str) -> Result<Self, String> {
+                    <$tp>::from_str(value).map_err(|e| e.to_string())
+                }
+            }
+        )*
+    };
+}
+
+from_str_reflected_string!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, char, String, Decimal
+);
+
+impl FromReflectedString for bool {
+    fn from_reflected_string(value: &str) -> Result<Self, String> {
+        match value {
+            "0" => Ok(false),
+            "1" => Ok(true),
+            _ => Err("expected `0` or `1`".to_string()),
+        }
+    }
+}
+
+impl FromReflectedString for NaiveDateTime {
+    fn from_reflected_string(value: &str) -> Result<Self, String> {
+        NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f").map_err(|e| e.to_string())
+    }
+}
+
+impl<T: ReflectedEnum> FromReflectedString for T {
+    fn from_reflected_string(value: &str) -> Result<Self, String> {
+        T::from_variant_name(value).ok_or_else(|| format!("Invalid {} variant: {value}", T::type_name()))
+    }
+}
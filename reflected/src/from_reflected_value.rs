@@ -0,0 +1,137 @@
+use chrono::NaiveDateTime;
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+
+use crate::{FromReflectedString, ReflectedEnum, Value};
+
+/// Inverse of `ToReflectedValue`
+///
+/// `Value::Text` is parsed with `FromReflectedString` for every type,
+/// so string based API can be built on top of it.
+pub trait FromReflectedValue: Sized {
+    fn from_reflected_value(value: Value) -> Result<Self, String>;
+}
+
+fn unexpected<T>(expected: &str, value: &Value) -> Result<T, String> {
+    Err(format!("expected {expected}, got {}", value.kind()))
+}
+
+macro_rules! integer_from_reflected_value {
+    ($($tp:ty),*) => {
+        $(
+            impl FromReflectedValue for $tp {
+                fn from_reflected_value(value: Value) -> Result<Self, String> {
+                    match value {
+                        Value::Int(int) => <$tp>::try_from(int).map_err(|e| e.to_string()),
+                        Value::UInt(int) => <$tp>::try_from(int).map_err(|e| e.to_string()),
+                        Value::Text(text) => <$tp>::from_reflected_string(&text),
+                        _ => unexpected("integer", &value),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+integer_from_reflected_value!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Large integers round to the nearest `f64`
+impl FromReflectedValue for f64 {
+    #[allow(clippy::cast_precision_loss)]
+    fn from_reflected_value(value: Value) -> Result<Self, String> {
+        match value {
+            Value::Float(float) => Ok(float),
+            Value::Int(int) => Ok(int as f64),
+            Value::UInt(int) => Ok(int as f64),
+            Value::Decimal(decimal) => decimal.to_f64().ok_or_else(|| format!("{decimal} does not fit f64")),
+            Value::Text(text) => f64::from_reflected_string(&text),
+            _ => unexpected("float", &value),
+        }
+    }
+}
+
+/// Values round to the nearest `f32`, out of range ones become infinite
+impl FromReflectedValue for f32 {
+    #[allow(clippy::cast_possible_truncation)]
+    fn from_reflected_value(value: Value) -> Result<Self, String> {
+        match value {
+            Value::Text(text) => f32::from_reflected_string(&text),
+            value => f64::from_reflected_value(value).map(|float| float as f32),
+        }
+    }
+}
+
+impl FromReflectedValue for Decimal {
+    fn from_reflected_value(value: Value) -> Result<Self, String> {
+        match value {
+            Value::Decimal(decimal) => Ok(decimal),
+            Value::Int(int) => Decimal::from_i128(int).ok_or_else(|| format!("{int} does not fit Decimal")),
+            Value::UInt(int) => Decimal::from_u128(int).ok_or_else(|| format!("{int} does not fit Decimal")),
+            Value::Float(float) => {
+                Decimal::from_f64(float).ok_or_else(|| format!("{float} does not fit Decimal"))
+            }
+            Value::Text(text) => Decimal::from_reflected_string(&text),
+            _ => unexpected("decimal", &value),
+        }
+    }
+}
+
+impl FromReflectedValue for bool {
+    fn from_reflected_value(value: Value) -> Result<Self, String> {
+        match value {
+            Value::Bool(bool) => Ok(bool),
+            Value::Int(0) | Value::UInt(0) => Ok(false),
+            Value::Int(1) | Value::UInt(1) => Ok(true),
+            Value::Text(text) => bool::from_reflected_string(&text),
+            _ => unexpected("bool", &value),
+        }
+    }
+}
+
+impl FromReflectedValue for NaiveDateTime {
+    fn from_reflected_value(value: Value) -> Result<Self, String> {
+        match value {
+            Value::Date(date) => Ok(date),
+            Value::Text(text) => NaiveDateTime::from_reflected_string(&text),
+            _ => unexpected("date", &value),
+        }
+    }
+}
+
+impl FromReflectedValue for String {
+    fn from_reflected_value(value: Value) -> Result<Self, String> {
+        match value {
+            Value::Text(text) => Ok(text),
+            _ => unexpected("text", &value),
+        }
+    }
+}
+
+impl FromReflectedValue for char {
+    fn from_reflected_value(value: Value) -> Result<Self, String> {
+        match value {
+            Value::Text(text) => char::from_reflected_string(&text),
+            _ => unexpected("char", &value),
+        }
+    }
+}
+
+impl<T: ReflectedEnum> FromReflectedValue for T {
+    fn from_reflected_value(value: Value) -> Result<Self, String> {
+        match value {
+            Value::Text(text) => T::from_reflected_string(&text),
+            _ => unexpected(T::type_name(), &value),
+        }
+    }
+}
+
+impl<T: FromReflectedValue> FromReflectedValue for Option<T> {
+    fn from_reflected_value(value: Value) -> Result<Self, String> {
+        match value {
+            Value::Null => Ok(None),
+            value => T::from_reflected_value(value).map(Some),
+        }
+    }
+}
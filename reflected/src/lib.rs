@@ -1,15 +1,66 @@
+mod codec;
+pub mod csv;
+mod dyn_reflected;
+mod error;
 mod field;
 mod field_type;
-mod random;
+mod from_reflected_string;
+mod from_reflected_value;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck;
+pub mod random;
+mod reference;
 mod reflected;
+mod reflected_enum;
 mod reflected_eq;
+pub mod schema;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod sql;
 mod to_reflected_string;
 mod to_reflected_val;
+mod to_reflected_value;
+mod typed_field;
+mod value;
 
+pub use codec::{HumanCodec, JsonCodec, LenientCodec, ReflectedCodec, SqlCodec};
+pub use dyn_reflected::DynReflected;
+pub use error::Error;
 pub use field::{Field, FieldRef};
 pub use field_type::{OptionalType, Type};
+pub use from_reflected_string::FromReflectedString;
+pub use from_reflected_value::FromReflectedValue;
+#[cfg(feature = "proptest")]
+pub use proptest::strategy;
+pub use rand;
+pub use random::{RandomRange, RandomValue};
+pub use reference::Reference;
 pub use reflected::Reflected;
+pub use reflected_enum::ReflectedEnum;
 pub use reflected_eq::ReflectedEq;
 pub use reflected_proc::Reflected;
 pub use to_reflected_string::ToReflectedString;
 pub use to_reflected_val::ToReflectedVal;
+pub use to_reflected_value::ToReflectedValue;
+pub use typed_field::TypedField;
+pub use value::Value;
+
+/// `#[reflected(arbitrary)]` doesn't implement `proptest::Arbitrary` without
+/// `proptest` feature
+#[cfg(not(feature = "proptest"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __proptest_arbitrary {
+    ($tp:ty) => {};
+}
+
+/// `#[reflected(arbitrary)]` doesn't implement `quickcheck::Arbitrary` without
+/// `quickcheck` feature
+#[cfg(not(feature = "quickcheck"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __quickcheck_arbitrary {
+    ($tp:ty) => {};
+}
@@ -0,0 +1,133 @@
+//! `proptest` strategies for `Reflected` types, enabled with `proptest` feature
+
+use std::fmt::Debug;
+
+pub use ::proptest::{arbitrary::Arbitrary, strategy::BoxedStrategy};
+use ::proptest::{option, prelude::any, sample::select, strategy::Strategy};
+use chrono::DateTime;
+use rust_decimal::Decimal;
+
+use crate::{schema::Property, DynReflected, Reflected, Type, Value};
+
+/// Field values of a struct in `Definition::properties` order
+#[derive(Debug, Clone)]
+enum Node {
+    Value(Value),
+    Struct(Option<Vec<Node>>),
+}
+
+/// Strategy building `T` from `fields()`, custom fields keep default values
+pub fn strategy<T: Reflected + Debug>() -> BoxedStrategy<T> {
+    struct_strategy(&mut T::default())
+        .prop_map(|nodes| {
+            let mut value = T::default();
+            apply(&mut value, nodes);
+            value
+        })
+        .boxed()
+}
+
+fn struct_strategy(value: &mut dyn DynReflected) -> BoxedStrategy<Vec<Node>> {
+    let properties = value.dyn_definition().properties;
+
+    let strategies: Vec<BoxedStrategy<Node>> = properties
+        .iter()
+        .map(|property| {
+            if !property.tp.is_struct() {
+                let strategy = value_strategy(property);
+                return if property.optional {
+                    option::of(strategy).prop_map(|a| Node::Value(a.unwrap_or(Value::Null))).boxed()
+                } else {
+                    strategy.prop_map(Node::Value).boxed()
+                };
+            }
+
+            let nested = value.dyn_nested_mut(&property.name).expect("Nested struct");
+            let strategy = struct_strategy(nested);
+
+            if property.optional {
+                option::of(strategy).prop_map(Node::Struct).boxed()
+            } else {
+                strategy.prop_map(|a| Node::Struct(Some(a))).boxed()
+            }
+        })
+        .collect();
+
+    strategies.boxed()
+}
+
+/// `None` for optional nested struct keeps the default value
+fn apply(value: &mut dyn DynReflected, nodes: Vec<Node>) {
+    let properties = value.dyn_definition().properties;
+
+    for (property, node) in properties.iter().zip(nodes) {
+        match node {
+            Node::Value(val) => value
+                .dyn_set_val(&property.name, val)
+                .unwrap_or_else(|e| panic!("Generated invalid value: {e}")),
+            Node::Struct(Some(nodes)) => apply(value.dyn_nested_mut(&property.name).unwrap(), nodes),
+            Node::Struct(None) => (),
+        }
+    }
+}
+
+/// Floats are finite so generated values compare equal to themselves
+fn value_strategy(property: &Property) -> BoxedStrategy<Value> {
+    use ::proptest::num::{f32, f64};
+
+    match property.tp {
+        Type::Integer => match property.type_name.as_str() {
+            "i8" => any::<i8>().prop_map(Value::from).boxed(),
+            "i16" => any::<i16>().prop_map(Value::from).boxed(),
+            "i32" => any::<i32>().prop_map(Value::from).boxed(),
+            "i64" => any::<i64>().prop_map(Value::from).boxed(),
+            "i128" => any::<i128>().prop_map(Value::from).boxed(),
+            "isize" => any::<isize>().prop_map(Value::from).boxed(),
+            "u16" => any::<u16>().prop_map(Value::from).boxed(),
+            "u32" => any::<u32>().prop_map(Value::from).boxed(),
+            "u64" => any::<u64>().prop_map(Value::from).boxed(),
+            "u128" => any::<u128>().prop_map(Value::from).boxed(),
+            "usize" => any::<usize>().prop_map(Value::from).boxed(),
+            // `u8`, aliases are resolved through `#[reflected(type = "...")]`
+            _ => any::<u8>().prop_map(Value::from).boxed(),
+        },
+        Type::Float if property.type_name == "f32" => {
+            (f32::NORMAL | f32::ZERO).prop_map(|a| Value::Float(a.into())).boxed()
+        }
+        Type::Float => (f64::NORMAL | f64::ZERO).prop_map(Value::Float).boxed(),
+        Type::Text if property.type_name == "char" => {
+            any::<char>().prop_map(|a| Value::Text(a.to_string())).boxed()
+        }
+        Type::Text => any::<String>().prop_map(Value::Text).boxed(),
+        Type::Bool => any::<bool>().prop_map(Value::Bool).boxed(),
+        // 1970 - 2100 with microsecond precision
+        Type::Date => (0..4_102_444_800_000_000_i64)
+            .prop_map(|micros| {
+                Value::Date(DateTime::from_timestamp_micros(micros).expect("Timestamp in range").naive_utc())
+            })
+            .boxed(),
+        Type::Decimal => (any::<i64>(), 0..=10_u32)
+            .prop_map(|(mantissa, scale)| Value::Decimal(Decimal::new(mantissa, scale)))
+            .boxed(),
+        Type::Enum => select(property.variants.clone()).prop_map(Value::Text).boxed(),
+        Type::Struct | Type::Custom | Type::Optional(_) => {
+            unreachable!("Not a value type: {:?}", property.tp)
+        }
+    }
+}
+
+/// Used by `#[reflected(arbitrary)]`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __proptest_arbitrary {
+    ($tp:ty) => {
+        impl $crate::proptest::Arbitrary for $tp {
+            type Parameters = ();
+            type Strategy = $crate::proptest::BoxedStrategy<Self>;
+
+            fn arbitrary_with((): ()) -> Self::Strategy {
+                $crate::proptest::strategy::<Self>()
+            }
+        }
+    };
+}
@@ -0,0 +1,193 @@
+//! `quickcheck` generation and shrinking for `Reflected` types, enabled with
+//! `quickcheck` feature
+
+pub use ::quickcheck::{Arbitrary, Gen};
+use chrono::DateTime;
+use rust_decimal::Decimal;
+
+use crate::{schema::Property, DynReflected, Reflected, Type, Value};
+
+/// 1970 - 2100
+const MAX_DATE_MICROS: i64 = 4_102_444_800_000_000;
+
+/// Random `T` built from `fields()`, custom fields keep default values
+///
+/// Optional fields are `None` half of the time, floats are finite.
+pub fn arbitrary<T: Reflected>(g: &mut Gen) -> T {
+    let mut value = T::default();
+    fill(&mut value, g);
+    value
+}
+
+fn fill(value: &mut dyn DynReflected, g: &mut Gen) {
+    for property in value.dyn_definition().properties {
+        if property.optional && !bool::arbitrary(g) {
+            if !property.tp.is_struct() {
+                value.dyn_set_val(&property.name, Value::Null).unwrap();
+            }
+            continue;
+        }
+
+        if property.tp.is_struct() {
+            fill(value.dyn_nested_mut(&property.name).unwrap(), g);
+            continue;
+        }
+
+        value
+            .dyn_set_val(&property.name, arbitrary_value(&property, g))
+            .unwrap_or_else(|e| panic!("Generated invalid value: {e}"));
+    }
+}
+
+fn arbitrary_value(property: &Property, g: &mut Gen) -> Value {
+    match property.tp {
+        Type::Integer => match property.type_name.as_str() {
+            "i8" => i8::arbitrary(g).into(),
+            "i16" => i16::arbitrary(g).into(),
+            "i32" => i32::arbitrary(g).into(),
+            "i64" => i64::arbitrary(g).into(),
+            "i128" => i128::arbitrary(g).into(),
+            "isize" => isize::arbitrary(g).into(),
+            "u16" => u16::arbitrary(g).into(),
+            "u32" => u32::arbitrary(g).into(),
+            "u64" => u64::arbitrary(g).into(),
+            "u128" => u128::arbitrary(g).into(),
+            "usize" => usize::arbitrary(g).into(),
+            // `u8`, aliases are resolved through `#[reflected(type = "...")]`
+            _ => u8::arbitrary(g).into(),
+        },
+        Type::Float if property.type_name == "f32" => Value::Float(finite(f32::arbitrary(g).into())),
+        Type::Float => Value::Float(finite(f64::arbitrary(g))),
+        Type::Text if property.type_name == "char" => Value::Text(char::arbitrary(g).to_string()),
+        Type::Text => Value::Text(String::arbitrary(g)),
+        Type::Bool => Value::Bool(bool::arbitrary(g)),
+        Type::Date => date(i64::arbitrary(g).rem_euclid(MAX_DATE_MICROS)),
+        Type::Decimal => Value::Decimal(Decimal::new(i64::arbitrary(g), u32::arbitrary(g) % 11)),
+        Type::Enum => Value::Text(g.choose(&property.variants).expect("Enum without variants").clone()),
+        Type::Struct | Type::Custom | Type::Optional(_) => {
+            unreachable!("Not a value type: {:?}", property.tp)
+        }
+    }
+}
+
+/// Copies of `value` with one field shrunk each, nested structs included
+///
+/// Optional fields shrink to `None` first, numbers shrink towards zero, text
+/// towards empty and enums to the first variant.
+pub fn shrink<T: Reflected + Clone + 'static>(value: &T) -> Box<dyn Iterator<Item = T>> {
+    let mut candidates = vec![];
+    collect_candidates(value, &mut vec![], &mut candidates);
+
+    let value = value.clone();
+
+    Box::new(candidates.into_iter().flat_map(move |(path, values)| {
+        let value = value.clone();
+        values.map(move |val| {
+            let mut res = value.clone();
+            set_by_path(&mut res, &path, val);
+            res
+        })
+    }))
+}
+
+type Candidates = Vec<(Vec<String>, Box<dyn Iterator<Item = Value>>)>;
+
+fn collect_candidates(value: &dyn DynReflected, path: &mut Vec<String>, candidates: &mut Candidates) {
+    for property in value.dyn_definition().properties {
+        path.push(property.name.clone());
+
+        if property.tp.is_struct() {
+            if let Some(nested) = value.dyn_nested(&property.name).unwrap() {
+                collect_candidates(nested, path, candidates);
+            }
+        } else {
+            let val = value.dyn_get_val(&property.name).unwrap();
+            candidates.push((path.clone(), shrink_value(&property, val)));
+        }
+
+        path.pop();
+    }
+}
+
+fn set_by_path(value: &mut dyn DynReflected, path: &[String], val: Value) {
+    match path {
+        [name] => value.dyn_set_val(name, val).unwrap(),
+        [name, rest @ ..] => set_by_path(value.dyn_nested_mut(name).unwrap(), rest, val),
+        [] => unreachable!("Empty field path"),
+    }
+}
+
+fn shrink_value(property: &Property, value: Value) -> Box<dyn Iterator<Item = Value>> {
+    let shrunk: Box<dyn Iterator<Item = Value>> = match value {
+        Value::Null => return ::quickcheck::empty_shrinker(),
+        // `-MIN` doesn't fit the field type
+        Value::Int(a) => Box::new(shrink_int(a).filter(move |b| a >= 0 || *b <= 0).map(Value::Int)),
+        Value::UInt(a) => Box::new(a.shrink().map(Value::UInt)),
+        Value::Float(a) => Box::new(shrink_float(a).map(Value::Float)),
+        Value::Text(a) if property.type_name == "char" => {
+            let ch = a.chars().next().unwrap_or_default();
+            Box::new(ch.shrink().map(|a| Value::Text(a.to_string())))
+        }
+        Value::Text(a) if property.tp.is_enum() => match property.variants.first() {
+            Some(first) if *first != a => ::quickcheck::single_shrinker(Value::Text(first.clone())),
+            _ => ::quickcheck::empty_shrinker(),
+        },
+        Value::Text(a) => Box::new(a.shrink().map(Value::Text)),
+        Value::Bool(a) => Box::new(a.shrink().map(Value::Bool)),
+        Value::Date(a) => Box::new(shrink_int(a.and_utc().timestamp_micros()).map(date)),
+        Value::Decimal(a) => {
+            let scale = a.scale();
+            Box::new(
+                shrink_int(a.mantissa())
+                    .map(move |mantissa| Value::Decimal(Decimal::from_i128_with_scale(mantissa, scale))),
+            )
+        }
+    };
+
+    if property.optional {
+        Box::new(std::iter::once(Value::Null).chain(shrunk))
+    } else {
+        shrunk
+    }
+}
+
+/// `shrink` of signed integers repeats `MIN` endlessly
+fn shrink_int<T: Arbitrary + Copy + PartialEq>(value: T) -> impl Iterator<Item = T> {
+    value.shrink().take_while(move |a| *a != value)
+}
+
+/// `f64::shrink` goes through `i64` which drops fractions and saturates
+fn shrink_float(value: f64) -> impl Iterator<Item = f64> {
+    [0.0, value.trunc(), value / 2.0]
+        .into_iter()
+        .filter(move |a| a.abs() < value.abs())
+}
+
+fn finite(value: f64) -> f64 {
+    if value.is_finite() {
+        value
+    } else {
+        0.0
+    }
+}
+
+fn date(micros: i64) -> Value {
+    Value::Date(DateTime::from_timestamp_micros(micros).expect("Timestamp in range").naive_utc())
+}
+
+/// Used by `#[reflected(arbitrary)]`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __quickcheck_arbitrary {
+    ($tp:ty) => {
+        impl $crate::quickcheck::Arbitrary for $tp {
+            fn arbitrary(g: &mut $crate::quickcheck::Gen) -> Self {
+                $crate::quickcheck::arbitrary(g)
+            }
+
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                $crate::quickcheck::shrink(self)
+            }
+        }
+    };
+}
@@ -1,30 +1,270 @@
-use chrono::Utc;
+//! Random values for `Reflected::random` and helpers used by
+//! `#[reflected(random(...))]`
+
+use std::ops::{Bound, RangeBounds};
+
+use chrono::{DateTime, NaiveDateTime};
 use rand::{
     distributions::{Alphanumeric, DistString},
+    seq::SliceRandom,
     thread_rng, Rng,
 };
 use rust_decimal::Decimal;
 
-use crate::Type;
-
-pub(crate) fn random_val(tp: &Type) -> Option<String> {
-    let mut rng = thread_rng();
-
-    match tp {
-        Type::Text => Alphanumeric.sample_string(&mut rng, 8).into(),
-        Type::Integer | Type::Float => rng.gen_range(0..1_000_000_000).to_string().into(),
-        Type::Date => Utc::now().naive_utc().to_string().into(),
-        Type::Decimal => Decimal::new(rng.gen_range(u32::MIN..u32::MAX).into(), rng.gen_range(1..6))
-            .to_string()
-            .into(),
-        Type::Bool => rng.gen_range(0..2).to_string().into(),
-        Type::Optional(opt) => {
-            if rng.gen() {
-                random_val(&opt.to_type())
-            } else {
-                None
+use crate::{codec::lenient_date, ReflectedEnum};
+
+/// Random value generation used by `Reflected::random`
+///
+/// All randomness comes from the passed RNG so values generated with a
+/// seeded RNG are reproducible.
+pub trait RandomValue: Sized {
+    fn random_value_with<R: Rng + ?Sized>(rng: &mut R) -> Self;
+
+    fn random_value() -> Self {
+        Self::random_value_with(&mut thread_rng())
+    }
+}
+
+/// Integers are generated in `0..=1_000_000_000` range or up to the type's
+/// max value for narrower types
+macro_rules! random_integer {
+    ($($tp:ty),*) => {
+        $(
+            impl RandomValue for $tp {
+                fn random_value_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                    const LIMIT: u128 = 1_000_000_000;
+                    const MAX: $tp = if (<$tp>::MAX as u128) < LIMIT { <$tp>::MAX } else { LIMIT as $tp };
+                    rng.gen_range(0..=MAX)
+                }
             }
+        )*
+    };
+}
+
+random_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Up to `10_000` with 2 decimal places to fit `f32` precision
+impl RandomValue for f32 {
+    #[allow(clippy::cast_precision_loss)]
+    fn random_value_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen_range(0..1_000_000) as f32 / 100.0
+    }
+}
+
+/// Up to `1_000_000` with 2 decimal places
+impl RandomValue for f64 {
+    fn random_value_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        f64::from(rng.gen_range(0..100_000_000)) / 100.0
+    }
+}
+
+impl RandomValue for bool {
+    fn random_value_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+}
+
+impl RandomValue for char {
+    fn random_value_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.sample(Alphanumeric).into()
+    }
+}
+
+impl RandomValue for String {
+    fn random_value_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Alphanumeric.sample_string(rng, 8)
+    }
+}
+
+/// Between 2000 and 2030 with microsecond precision
+impl RandomValue for NaiveDateTime {
+    fn random_value_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        const FROM: i64 = 946_684_800;
+        const TO: i64 = 1_893_456_000;
+
+        let micros: u32 = rng.gen_range(0..1_000_000);
+
+        DateTime::from_timestamp(rng.gen_range(FROM..TO), micros * 1000)
+            .expect("Timestamp in range")
+            .naive_utc()
+    }
+}
+
+impl RandomValue for Decimal {
+    fn random_value_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Decimal::new(rng.gen_range(u32::MIN..u32::MAX).into(), rng.gen_range(1..6))
+    }
+}
+
+impl<T: ReflectedEnum> RandomValue for T {
+    fn random_value_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let name = T::VARIANTS.choose(rng).expect("Enum without variants");
+        T::from_variant_name(name).expect("Invalid variant name")
+    }
+}
+
+impl<T: RandomValue> RandomValue for Option<T> {
+    fn random_value_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        if rng.gen() {
+            Some(T::random_value_with(rng))
+        } else {
+            None
         }
-        Type::Custom => unreachable!("Failed to gen random value for: {tp:?}"),
     }
 }
+
+/// Value within `#[reflected(random(range = a..b))]` bounds
+pub trait RandomRange: Sized {
+    /// Only `a..b` and `a..=b` ranges are supported
+    fn random_in<R: Rng + ?Sized>(rng: &mut R, range: impl RangeBounds<Self>) -> Self;
+}
+
+macro_rules! random_range {
+    ($($tp:ty),*) => {
+        $(
+            impl RandomRange for $tp {
+                fn random_in<R: Rng + ?Sized>(rng: &mut R, range: impl RangeBounds<Self>) -> Self {
+                    match (range.start_bound(), range.end_bound()) {
+                        (Bound::Included(start), Bound::Excluded(end)) => rng.gen_range(*start..*end),
+                        (Bound::Included(start), Bound::Included(end)) => rng.gen_range(*start..=*end),
+                        _ => panic!("Random range must be `a..b` or `a..=b`"),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+random_range!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// Uses the largest scale of the bounds, at least 2 decimal places
+impl RandomRange for Decimal {
+    fn random_in<R: Rng + ?Sized>(rng: &mut R, range: impl RangeBounds<Self>) -> Self {
+        let (start, end, inclusive) = bounds(&range);
+        let scale = start.scale().max(end.scale()).max(2);
+
+        let mantissa = |mut value: Decimal| {
+            value.rescale(scale);
+            value.mantissa()
+        };
+
+        let (start, end) = (mantissa(start), mantissa(end));
+        let value = if inclusive {
+            rng.gen_range(start..=end)
+        } else {
+            rng.gen_range(start..end)
+        };
+
+        Decimal::from_i128_with_scale(value, scale)
+    }
+}
+
+/// Microsecond precision
+impl RandomRange for NaiveDateTime {
+    fn random_in<R: Rng + ?Sized>(rng: &mut R, range: impl RangeBounds<Self>) -> Self {
+        let (start, end, inclusive) = bounds(&range);
+        let (start, end) = (
+            start.and_utc().timestamp_micros(),
+            end.and_utc().timestamp_micros(),
+        );
+
+        let micros = if inclusive {
+            rng.gen_range(start..=end)
+        } else {
+            rng.gen_range(start..end)
+        };
+
+        DateTime::from_timestamp_micros(micros).expect("Timestamp in range").naive_utc()
+    }
+}
+
+fn bounds<T: Copy>(range: &impl RangeBounds<T>) -> (T, T, bool) {
+    match (range.start_bound(), range.end_bound()) {
+        (Bound::Included(start), Bound::Excluded(end)) => (*start, *end, false),
+        (Bound::Included(start), Bound::Included(end)) => (*start, *end, true),
+        _ => panic!("Random range must be `a..b` or `a..=b`"),
+    }
+}
+
+/// Realistic text for `String` fields picked by field name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Faker {
+    FirstName,
+    LastName,
+    FullName,
+    Username,
+    Email,
+    Url,
+    Phone,
+}
+
+const FIRST_NAMES: &[&str] = &[
+    "Alice", "Bob", "Carol", "David", "Emma", "Frank", "Grace", "Henry", "Isla", "Jack", "Kate", "Liam",
+    "Mia", "Noah", "Olivia", "Peter", "Quinn", "Rose", "Sam", "Tina",
+];
+
+const LAST_NAMES: &[&str] = &[
+    "Smith", "Johnson", "Brown", "Taylor", "Miller", "Wilson", "Moore", "Clark", "Lewis", "Walker", "Hall",
+    "Young", "King", "Wright", "Scott", "Green", "Baker", "Adams", "Nelson", "Hill",
+];
+
+impl Faker {
+    /// Case, `_` and `-` are ignored: `email`, `user_email`, `firstName`,
+    /// `last_name`, `name`, `username`, `url`, `website`, `phone`
+    pub fn for_field(name: &str) -> Option<Self> {
+        let name = name.replace(['_', '-'], "").to_ascii_lowercase();
+
+        Some(match name.as_str() {
+            name if name.contains("email") => Self::Email,
+            name if name.contains("phone") => Self::Phone,
+            name if name.ends_with("url") || name.contains("website") => Self::Url,
+            "firstname" | "givenname" => Self::FirstName,
+            "lastname" | "surname" | "familyname" => Self::LastName,
+            "username" | "login" | "nickname" => Self::Username,
+            "name" | "fullname" | "displayname" => Self::FullName,
+            _ => return None,
+        })
+    }
+
+    pub fn generate<R: Rng + ?Sized>(self, rng: &mut R) -> String {
+        let first = FIRST_NAMES.choose(rng).unwrap();
+        let last = LAST_NAMES.choose(rng).unwrap();
+        let number: u16 = rng.gen_range(1..1000);
+
+        match self {
+            Self::FirstName => (*first).to_string(),
+            Self::LastName => (*last).to_string(),
+            Self::FullName => format!("{first} {last}"),
+            Self::Username => format!("{}{number}", first.to_lowercase()),
+            Self::Email => format!(
+                "{}.{}{number}@example.com",
+                first.to_lowercase(),
+                last.to_lowercase()
+            ),
+            Self::Url => format!(
+                "https://example.com/{}",
+                Alphanumeric.sample_string(rng, 8).to_lowercase()
+            ),
+            Self::Phone => format!("+1-555-{:03}-{:04}", number, rng.gen_range(0..10_000)),
+        }
+    }
+}
+
+/// Alphanumeric text with length in `len` range
+pub fn text_with_len<R: Rng + ?Sized>(rng: &mut R, len: impl RangeBounds<usize>) -> String {
+    let len = usize::random_in(rng, len);
+    Alphanumeric.sample_string(rng, len)
+}
+
+/// `Faker` text if field name is recognized, alphanumeric otherwise
+pub fn text_for_field<R: Rng + ?Sized>(rng: &mut R, name: &str) -> String {
+    match Faker::for_field(name) {
+        Some(faker) => faker.generate(rng),
+        None => String::random_value_with(rng),
+    }
+}
+
+/// Bound of `date_range`, accepts formats of `LenientCodec`
+pub fn date_bound(value: &str) -> NaiveDateTime {
+    lenient_date(value).unwrap_or_else(|e| panic!("Invalid `date_range` bound: {e}"))
+}
@@ -0,0 +1,7 @@
+/// Struct referenced by a foreign key field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Reference {
+    pub type_name:   &'static str,
+    /// Names of primary key fields of the referenced struct
+    pub primary_key: &'static [&'static str],
+}
@@ -1,32 +1,205 @@
-use crate::{random::random_val, Field};
+use rand::{rngs::StdRng, thread_rng, Rng, SeedableRng};
+
+use crate::{DynReflected, Error, Field, LenientCodec, ReflectedCodec, Value};
 
 pub trait Reflected: Default + 'static {
-    fn type_name() -> &'static str;
+    const TYPE_NAME: &'static str;
+
+    /// Names of the fields marked with `#[reflected(id)]`
+    const PRIMARY_KEY: &'static [&'static str];
+
+    fn type_name() -> &'static str {
+        Self::TYPE_NAME
+    }
 
     fn fields() -> &'static [&'static Field<'static, Self>];
     fn simple_fields() -> &'static [&'static Field<'static, Self>];
 
-    fn get_value(&self, field: &'static Field<'static, Self>) -> String;
-    fn set_value(&mut self, field: &'static Field<'static, Self>, value: Option<&str>);
+    fn try_get_val(&self, field: &'static Field<'static, Self>) -> Result<Value, Error>;
+    fn try_set_val(&mut self, field: &'static Field<'static, Self>, value: Value) -> Result<(), Error>;
+
+    fn get_val(&self, field: &'static Field<'static, Self>) -> Value {
+        self.try_get_val(field).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn set_val(&mut self, field: &'static Field<'static, Self>, value: impl Into<Value>) {
+        self.try_set_val(field, value.into()).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    /// `NULL` for `Value::Null`, so it is indistinguishable from a `"NULL"`
+    /// text. Use `try_get_val` when it matters.
+    fn try_get_value(&self, field: &'static Field<'static, Self>) -> Result<String, Error> {
+        self.try_get_val(field).map(|value| value.to_string())
+    }
+
+    /// `None` is `Value::Null`, strings are parsed with `FromReflectedString`
+    /// or with `LenientCodec` if field is marked `#[reflected(lenient)]`
+    fn try_set_value(
+        &mut self,
+        field: &'static Field<'static, Self>,
+        value: Option<&str>,
+    ) -> Result<(), Error> {
+        if field.lenient {
+            return self.try_set_value_lenient(field, value).map(|_| ());
+        }
+        self.try_set_val(field, value.into())
+    }
+
+    /// Accepts alternate spellings, see `LenientCodec`.
+    /// Returns the value that was actually set.
+    fn try_set_value_lenient(
+        &mut self,
+        field: &'static Field<'static, Self>,
+        value: Option<&str>,
+    ) -> Result<Value, Error> {
+        let mut value = match value {
+            Some(text) => LenientCodec
+                .decode(&field.tp, text)
+                .map_err(|e| Error::parse(field.name, text, e))?,
+            None => Value::Null,
+        };
+
+        if field.is_enum() {
+            if let Some(variant) = value
+                .as_str()
+                .and_then(|text| field.variants.iter().find(|a| a.eq_ignore_ascii_case(text.trim())))
+            {
+                value = Value::Text((*variant).to_string());
+            }
+        }
+
+        self.try_set_val(field, value.clone())?;
+        Ok(value)
+    }
+
+    fn set_value_lenient(&mut self, field: &'static Field<'static, Self>, value: Option<&str>) -> Value {
+        self.try_set_value_lenient(field, value).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn primary_key_fields() -> Vec<&'static Field<'static, Self>> {
+        Self::fields().iter().copied().filter(|a| a.is_id()).collect()
+    }
+
+    fn foreign_keys() -> Vec<&'static Field<'static, Self>> {
+        Self::fields().iter().copied().filter(|a| a.is_foreign_id()).collect()
+    }
+
+    /// Fields stored in columns: everything except nested structs and custom
+    /// fields
+    fn column_fields() -> Vec<&'static Field<'static, Self>> {
+        Self::fields()
+            .iter()
+            .copied()
+            .filter(|a| !a.is_struct() && !a.is_custom())
+            .collect()
+    }
+
+    /// `None` if optional nested struct is not set
+    fn nested(&self, field: &'static Field<'static, Self>) -> Result<Option<&dyn DynReflected>, Error>;
+
+    /// Optional nested struct is initialized with default value if not set
+    fn nested_mut(&mut self, field: &'static Field<'static, Self>) -> Result<&mut dyn DynReflected, Error>;
+
+    fn get_value(&self, field: &'static Field<'static, Self>) -> String {
+        self.try_get_value(field).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn set_value(&mut self, field: &'static Field<'static, Self>, value: Option<&str>) {
+        self.try_set_value(field, value).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    fn try_get_value_with(
+        &self,
+        codec: &impl ReflectedCodec,
+        field: &'static Field<'static, Self>,
+    ) -> Result<String, Error> {
+        self.try_get_val(field).map(|value| codec.encode(&value))
+    }
+
+    fn get_value_with(&self, codec: &impl ReflectedCodec, field: &'static Field<'static, Self>) -> String {
+        self.try_get_value_with(codec, field).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn try_set_value_with(
+        &mut self,
+        codec: &impl ReflectedCodec,
+        field: &'static Field<'static, Self>,
+        value: &str,
+    ) -> Result<(), Error> {
+        let value = codec.decode(&field.tp, value).map_err(|e| Error::parse(field.name, value, e))?;
+        self.try_set_val(field, value)
+    }
+
+    fn set_value_with(
+        &mut self,
+        codec: &impl ReflectedCodec,
+        field: &'static Field<'static, Self>,
+        value: &str,
+    ) {
+        self.try_set_value_with(codec, field, value).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    fn try_field_by_name(name: &str) -> Result<&'static Field<'static, Self>, Error> {
+        Self::fields()
+            .iter()
+            .find(|a| a.name == name)
+            .copied()
+            .ok_or_else(|| Error::UnknownField {
+                type_name: Self::type_name(),
+                name:      name.to_string(),
+            })
+    }
 
     fn field_by_name(name: &str) -> &'static Field<'static, Self> {
-        Self::fields().iter().find(|a| a.name == name).unwrap()
+        Self::try_field_by_name(name).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn try_value_by_name(&self, name: &str) -> Result<String, Error> {
+        self.try_get_value(Self::try_field_by_name(name)?)
     }
 
     fn value_by_name(&self, name: &str) -> String {
         self.get_value(Self::field_by_name(name))
     }
 
-    fn random() -> Self {
-        let mut res = Self::default();
+    /// Path to a field of a nested struct is separated by dots: `address.city`
+    fn try_value_by_path(&self, path: &str) -> Result<String, Error> {
+        match path.split_once('.') {
+            Some((name, path)) => match self.nested(Self::try_field_by_name(name)?)? {
+                Some(nested) => nested.dyn_value_by_path(path),
+                None => Ok("NULL".to_string()),
+            },
+            None => self.try_value_by_name(path),
+        }
+    }
 
-        for field in Self::fields() {
-            if field.is_custom() {
-                continue;
-            }
-            res.set_value(field, random_val(&field.tp).as_deref());
+    fn value_by_path(&self, path: &str) -> String {
+        self.try_value_by_path(path).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn try_set_value_by_path(&mut self, path: &str, value: Option<&str>) -> Result<(), Error> {
+        match path.split_once('.') {
+            Some((name, path)) => self
+                .nested_mut(Self::try_field_by_name(name)?)?
+                .dyn_set_value_by_path(path, value),
+            None => self.try_set_value(Self::try_field_by_name(path)?, value),
         }
+    }
+
+    fn set_value_by_path(&mut self, path: &str, value: Option<&str>) {
+        self.try_set_value_by_path(path, value).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    /// Custom fields are left with their default values
+    fn random() -> Self {
+        Self::random_with(&mut thread_rng())
+    }
+
+    /// Every value including nested structs is generated with `rng`
+    fn random_with(rng: &mut (impl Rng + ?Sized)) -> Self;
 
-        res
+    /// Same seed gives the same value as long as `rand` version is the same
+    fn random_seeded(seed: u64) -> Self {
+        Self::random_with(&mut StdRng::seed_from_u64(seed))
     }
 }
@@ -0,0 +1,11 @@
+/// Implemented by `#[derive(Reflected)]` on fieldless enums
+pub trait ReflectedEnum: Sized + 'static {
+    const VARIANTS: &'static [&'static str];
+
+    fn type_name() -> &'static str;
+
+    fn variants() -> &'static [Self];
+
+    fn variant_name(&self) -> &'static str;
+    fn from_variant_name(name: &str) -> Option<Self>;
+}
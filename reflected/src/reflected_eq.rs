@@ -7,10 +7,27 @@ pub trait ReflectedEq {
 impl<T: Reflected> ReflectedEq for T {
     fn assert_eq(&self, other: &Self) {
         for field in T::fields() {
+            if field.is_custom() {
+                continue;
+            }
+
+            if field.is_struct() {
+                match (self.nested(field).unwrap(), other.nested(field).unwrap()) {
+                    (Some(a), Some(b)) => a.dyn_assert_eq(b),
+                    (None, None) => (),
+                    (a, b) => panic!(
+                        "Reflected eq error: Field: {field:?}\nLeft is null: {}, Right is null: {}",
+                        a.is_none(),
+                        b.is_none()
+                    ),
+                }
+                continue;
+            }
+
             let a = self.get_value(field);
             let b = other.get_value(field);
 
-            if field.is_float() || field.is_decimal() {
+            if (field.is_float() || field.is_decimal()) && a != "NULL" && b != "NULL" {
                 let a: f64 = a.parse().unwrap();
                 let b: f64 = b.parse().unwrap();
 
@@ -0,0 +1,101 @@
+use crate::{DynReflected, Field, Reflected, Type};
+
+/// Description of a `Reflected` struct including nested structs
+///
+/// Used to generate documents describing the type itself rather than its
+/// table, like JSON Schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Definition {
+    pub name:       String,
+    pub properties: Vec<Property>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Property {
+    pub name:        String,
+    /// Never `Type::Optional`, see `optional`
+    pub tp:          Type,
+    /// `Reflected::type_name` for nested structs, so `rename` applies, and
+    /// `Field::value_type_name` for others
+    pub type_name:   String,
+    pub optional:    bool,
+    pub primary_key: bool,
+    pub variants:    Vec<String>,
+    /// Field number in `.proto`
+    pub tag:         u32,
+}
+
+impl Definition {
+    /// Custom fields are skipped
+    pub fn of<T: Reflected>() -> Self {
+        Self {
+            name:       T::type_name().to_string(),
+            properties: T::fields().iter().filter_map(|field| Property::from_field(field)).collect(),
+        }
+    }
+
+    /// `T` first, then nested structs in order of appearance, each type once
+    pub fn all<T: Reflected>() -> Vec<Self> {
+        let mut definitions = vec![];
+        collect(&mut T::default(), &mut definitions);
+        definitions
+    }
+}
+
+impl Property {
+    pub fn from_field<T>(field: &Field<T>) -> Option<Self> {
+        let tp = match field.tp {
+            Type::Custom => return None,
+            Type::Optional(tp) => tp.to_type(),
+            tp => tp,
+        };
+
+        Some(Self {
+            name: field.name.to_string(),
+            tp,
+            type_name: field.value_type_name().to_string(),
+            optional: field.optional,
+            primary_key: field.is_id(),
+            variants: field.variants.iter().map(ToString::to_string).collect(),
+            tag: field.tag,
+        })
+    }
+}
+
+/// Enum properties with distinct types in order of appearance
+pub(crate) fn enums(definitions: &[Definition]) -> Vec<&Property> {
+    let mut enums: Vec<&Property> = vec![];
+
+    for property in definitions.iter().flat_map(|a| &a.properties).filter(|a| a.tp.is_enum()) {
+        if !enums.iter().any(|a| a.type_name == property.type_name) {
+            enums.push(property);
+        }
+    }
+
+    enums
+}
+
+/// Nested structs are reached through `dyn_nested_mut` which creates
+/// optional ones, so a default value is enough to describe all of them
+fn collect(value: &mut dyn DynReflected, definitions: &mut Vec<Definition>) {
+    let definition = value.dyn_definition();
+
+    if definitions.iter().any(|a| a.name == definition.name) {
+        return;
+    }
+
+    let nested: Vec<_> = definition
+        .properties
+        .iter()
+        .filter(|a| a.tp.is_struct())
+        .map(|a| a.name.clone())
+        .collect();
+
+    definitions.push(definition);
+
+    for name in nested {
+        if let Ok(nested) = value.dyn_nested_mut(&name) {
+            collect(nested, definitions);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::schema::{Column, Schema};
+
+/// Steps to migrate a table from one `Schema` to another
+///
+/// Changes of primary and foreign keys are not detected.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Migration {
+    pub from:  Schema,
+    pub to:    Schema,
+    pub steps: Vec<MigrationStep>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MigrationStep {
+    RenameTable {
+        from: String,
+        to:   String,
+    },
+    AddColumn(Column),
+    DropColumn(Column),
+    /// New column definition, nullability is changed separately
+    ChangeType(Column),
+    /// New column definition
+    ChangeNullable(Column),
+}
+
+impl Migration {
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+impl Schema {
+    /// Columns are matched by name, renamed column is dropped and added again
+    pub fn diff(&self, to: &Schema) -> Migration {
+        let mut steps = vec![];
+
+        if self.name != to.name {
+            steps.push(MigrationStep::RenameTable {
+                from: self.name.clone(),
+                to:   to.name.clone(),
+            });
+        }
+
+        for column in &self.columns {
+            if to.column(&column.name).is_none() {
+                steps.push(MigrationStep::DropColumn(column.clone()));
+            }
+        }
+
+        for column in &to.columns {
+            let Some(old) = self.column(&column.name) else {
+                steps.push(MigrationStep::AddColumn(column.clone()));
+                continue;
+            };
+
+            if old.nullable != column.nullable {
+                steps.push(MigrationStep::ChangeNullable(column.clone()));
+            }
+
+            if old.tp != column.tp || old.type_name != column.type_name || old.variants != column.variants {
+                steps.push(MigrationStep::ChangeType(column.clone()));
+            }
+        }
+
+        Migration {
+            from: self.clone(),
+            to: to.clone(),
+            steps,
+        }
+    }
+}
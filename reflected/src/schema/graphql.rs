@@ -0,0 +1,73 @@
+use crate::{
+    schema::{
+        definition::{enums, Definition},
+        Property,
+    },
+    Reflected, Type,
+};
+
+/// GraphQL SDL object types of `T` and its nested structs
+///
+/// Primary keys are `ID`. GraphQL `Int` is 32 bit so wider integers use a
+/// `BigInt` scalar, dates and decimals use `DateTime` and `Decimal` scalars.
+/// Scalars are declared only when used.
+pub fn graphql<T: Reflected>() -> String {
+    let definitions = Definition::all::<T>();
+    let properties: Vec<_> = definitions.iter().flat_map(|a| &a.properties).collect();
+
+    let mut scalars: Vec<&str> = vec![];
+    for property in &properties {
+        let tp = property_type(property);
+        if matches!(tp, "BigInt" | "DateTime" | "Decimal") && !scalars.contains(&tp) {
+            scalars.push(tp);
+        }
+    }
+
+    let mut res: String = scalars.iter().map(|a| ["scalar ", a, "\n"].concat()).collect();
+
+    for property in enums(&definitions) {
+        if !res.is_empty() {
+            res.push('\n');
+        }
+        res.push_str(&format!("enum {} {{\n", property.type_name));
+        for variant in &property.variants {
+            res.push_str(&format!("  {variant}\n"));
+        }
+        res.push_str("}\n");
+    }
+
+    for definition in &definitions {
+        if !res.is_empty() {
+            res.push('\n');
+        }
+        res.push_str(&format!("type {} {{\n", definition.name));
+        for property in &definition.properties {
+            let required = if property.optional { "" } else { "!" };
+            res.push_str(&format!(
+                "  {}: {}{required}\n",
+                property.name,
+                property_type(property)
+            ));
+        }
+        res.push_str("}\n");
+    }
+
+    res
+}
+
+fn property_type(property: &Property) -> &str {
+    match property.tp {
+        _ if property.primary_key => "ID",
+        Type::Float => "Float",
+        Type::Integer => match property.type_name.as_str() {
+            "i8" | "i16" | "i32" | "u8" | "u16" => "Int",
+            _ => "BigInt",
+        },
+        Type::Text => "String",
+        Type::Bool => "Boolean",
+        Type::Date => "DateTime",
+        Type::Decimal => "Decimal",
+        Type::Enum | Type::Struct => &property.type_name,
+        Type::Custom | Type::Optional(_) => unreachable!("Not a property type: {:?}", property.tp),
+    }
+}
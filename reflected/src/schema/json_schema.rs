@@ -0,0 +1,95 @@
+use serde_json::{json, Map, Value};
+
+use crate::{
+    schema::{Definition, Property},
+    Reflected, Type,
+};
+
+const DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Draft 2020-12 JSON Schema of `T`, pretty printed, enabled with `serde`
+/// feature
+///
+/// Matches the format of `serde::AsSerde`: dates are `date-time` strings,
+/// decimals are strings to keep precision. Nested structs are referenced
+/// from `$defs`.
+pub fn json_schema<T: Reflected>() -> String {
+    let mut definitions = Definition::all::<T>().into_iter();
+    let root = definitions.next().expect("Definition::all always returns the root type");
+
+    let mut schema = object(&root);
+    schema["$schema"] = DRAFT.into();
+
+    let defs: Map<_, _> = definitions
+        .map(|definition| (definition.name.clone(), object(&definition)))
+        .collect();
+
+    if !defs.is_empty() {
+        schema["$defs"] = Value::Object(defs);
+    }
+
+    serde_json::to_string_pretty(&schema).expect("schema always serializes")
+}
+
+fn object(definition: &Definition) -> Value {
+    let properties: Map<_, _> = definition
+        .properties
+        .iter()
+        .map(|property| (property.name.clone(), property_schema(property)))
+        .collect();
+
+    let required: Vec<_> = definition
+        .properties
+        .iter()
+        .filter(|a| !a.optional)
+        .map(|a| a.name.as_str())
+        .collect();
+
+    json!({
+        "title": definition.name,
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    })
+}
+
+fn property_schema(property: &Property) -> Value {
+    if property.tp.is_struct() {
+        let reference = json!({ "$ref": format!("#/$defs/{}", property.type_name) });
+
+        return if property.optional {
+            json!({ "anyOf": [reference, { "type": "null" }] })
+        } else {
+            reference
+        };
+    }
+
+    let tp = match property.tp {
+        Type::Float => "number",
+        Type::Integer => "integer",
+        Type::Bool => "boolean",
+        _ => "string",
+    };
+
+    let mut schema = if property.optional {
+        json!({ "type": [tp, "null"] })
+    } else {
+        json!({ "type": tp })
+    };
+
+    match property.tp {
+        Type::Integer if property.type_name.starts_with('u') => schema["minimum"] = 0.into(),
+        Type::Date => schema["format"] = "date-time".into(),
+        Type::Decimal => schema["pattern"] = r"^-?[0-9]+(\.[0-9]+)?$".into(),
+        Type::Enum => {
+            let mut variants: Vec<_> = property.variants.iter().map(|a| Value::from(a.as_str())).collect();
+            if property.optional {
+                variants.push(Value::Null);
+            }
+            schema["enum"] = Value::Array(variants);
+        }
+        _ => (),
+    }
+
+    schema
+}
@@ -0,0 +1,19 @@
+//! Dialect independent description of `Reflected` types
+
+mod definition;
+mod diff;
+mod graphql;
+#[cfg(feature = "serde")]
+mod json_schema;
+mod proto;
+mod snapshot;
+mod typescript;
+
+pub use definition::{Definition, Property};
+pub use diff::{Migration, MigrationStep};
+pub use graphql::graphql;
+#[cfg(feature = "serde")]
+pub use json_schema::json_schema;
+pub use proto::proto;
+pub use snapshot::{Column, ForeignKey, Schema};
+pub use typescript::{typescript, TypeScript};
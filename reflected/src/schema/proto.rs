@@ -0,0 +1,94 @@
+use crate::{
+    schema::{
+        definition::{enums, Definition},
+        Property,
+    },
+    Reflected, Type,
+};
+
+/// `proto3` messages of `T` and its nested structs
+///
+/// Field numbers come from `Field::tag`. Dates are
+/// `google.protobuf.Timestamp`, decimals and 128 bit integers are strings.
+/// Enum values are prefixed with the enum name because protobuf enum values
+/// share the scope of the enum itself.
+pub fn proto<T: Reflected>() -> String {
+    let definitions = Definition::all::<T>();
+
+    let mut res = "syntax = \"proto3\";\n".to_string();
+
+    if definitions.iter().flat_map(|a| &a.properties).any(|a| a.tp.is_date()) {
+        res.push_str("\nimport \"google/protobuf/timestamp.proto\";\n");
+    }
+
+    for property in enums(&definitions) {
+        let prefix = screaming_snake_case(&property.type_name);
+
+        res.push_str(&format!("\nenum {} {{\n", property.type_name));
+        for (index, variant) in property.variants.iter().enumerate() {
+            res.push_str(&format!(
+                "  {prefix}_{} = {index};\n",
+                screaming_snake_case(variant)
+            ));
+        }
+        res.push_str("}\n");
+    }
+
+    for definition in &definitions {
+        res.push_str(&format!("\nmessage {} {{\n", definition.name));
+        for property in &definition.properties {
+            let optional = if property.optional { "optional " } else { "" };
+            res.push_str(&format!(
+                "  {optional}{} {} = {};\n",
+                property_type(property),
+                property.name,
+                property.tag
+            ));
+        }
+        res.push_str("}\n");
+    }
+
+    res
+}
+
+fn property_type(property: &Property) -> &str {
+    match property.tp {
+        Type::Float if property.type_name == "f32" => "float",
+        Type::Float => "double",
+        Type::Integer => match property.type_name.as_str() {
+            "i8" | "i16" | "i32" => "int32",
+            "u8" | "u16" | "u32" => "uint32",
+            "u64" | "usize" => "uint64",
+            "i128" | "u128" => "string",
+            _ => "int64",
+        },
+        Type::Text | Type::Decimal => "string",
+        Type::Bool => "bool",
+        Type::Date => "google.protobuf.Timestamp",
+        Type::Enum | Type::Struct => &property.type_name,
+        Type::Custom | Type::Optional(_) => unreachable!("Not a property type: {:?}", property.tp),
+    }
+}
+
+/// `OrderStatus` -> `ORDER_STATUS`
+fn screaming_snake_case(name: &str) -> String {
+    let mut res = String::new();
+    let mut previous_lower = false;
+
+    for char in name.chars() {
+        if !char.is_ascii_alphanumeric() {
+            res.push('_');
+            previous_lower = false;
+            continue;
+        }
+
+        if char.is_ascii_uppercase() && previous_lower {
+            res.push('_');
+        }
+
+        previous_lower = char.is_ascii_lowercase() || char.is_ascii_digit();
+        res.push(char.to_ascii_uppercase());
+    }
+
+    res
+}
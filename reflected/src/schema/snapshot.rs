@@ -0,0 +1,241 @@
+use crate::{Field, Reflected, Type};
+
+/// Snapshot of a table stored by a `Reflected` type
+///
+/// Serialized to JSON with `to_json` (`serde` feature) so it can be checked
+/// into git and compared with the next version of the type using
+/// `Schema::diff`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schema {
+    pub name:    String,
+    pub columns: Vec<Column>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    pub name:        String,
+    /// Never `Type::Optional`, see `nullable`
+    pub tp:          Type,
+    /// `Field::value_type_name`, so aliases get the width of their override
+    pub type_name:   String,
+    pub nullable:    bool,
+    pub primary_key: bool,
+    pub foreign_key: Option<ForeignKey>,
+    pub variants:    Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForeignKey {
+    pub table:   String,
+    pub columns: Vec<String>,
+}
+
+impl Schema {
+    /// Nested structs and custom fields are not stored in columns and are
+    /// skipped
+    pub fn of<T: Reflected>() -> Self {
+        Self {
+            name:    T::type_name().to_string(),
+            columns: T::fields().iter().filter_map(|field| Column::from_field(field)).collect(),
+        }
+    }
+
+    pub fn column(&self, name: &str) -> Option<&Column> {
+        self.columns.iter().find(|a| a.name == name)
+    }
+
+    pub fn primary_key(&self) -> Vec<&str> {
+        self.columns.iter().filter(|a| a.primary_key).map(|a| a.name.as_str()).collect()
+    }
+}
+
+impl Column {
+    /// `None` for nested structs and custom fields
+    ///
+    /// Foreign key without `foreign_key = "Type"` references `id` of a table
+    /// named after the field: `user_id` -> `User`
+    pub fn from_field<T>(field: &Field<T>) -> Option<Self> {
+        let tp = match field.tp {
+            Type::Struct | Type::Custom => return None,
+            Type::Optional(tp) if tp.to_type().is_struct() => return None,
+            Type::Optional(tp) => tp.to_type(),
+            tp => tp,
+        };
+
+        let foreign_key = field.is_foreign_id().then(|| match field.references {
+            Some(reference) => ForeignKey {
+                table:   reference.type_name.to_string(),
+                columns: reference.primary_key.iter().map(ToString::to_string).collect(),
+            },
+            None => ForeignKey {
+                table:   guess_table(field.name),
+                columns: vec!["id".to_string()],
+            },
+        });
+
+        Some(Self {
+            name: field.name.to_string(),
+            tp,
+            type_name: field.value_type_name().to_string(),
+            nullable: field.optional,
+            primary_key: field.is_id(),
+            foreign_key,
+            variants: field.variants.iter().map(ToString::to_string).collect(),
+        })
+    }
+}
+
+/// `user_id` -> `User`
+fn guess_table(field: &str) -> String {
+    field
+        .strip_suffix("_id")
+        .unwrap_or(field)
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map_or(String::new(), |first| first.to_uppercase().chain(chars).collect())
+        })
+        .collect()
+}
+
+#[cfg(feature = "serde")]
+mod json {
+    use serde::{ser::SerializeStruct, Serialize, Serializer};
+    use serde_json::Value as Json;
+
+    use super::{Column, ForeignKey, Schema};
+    use crate::{Error, Type};
+
+    impl Schema {
+        pub fn to_json(&self) -> String {
+            serde_json::to_string_pretty(self).expect("snapshot always serializes")
+        }
+
+        pub fn from_json(text: &str) -> Result<Self, Error> {
+            let json: Json = serde_json::from_str(text).map_err(invalid)?;
+
+            Ok(Self {
+                name:    string(&json, "name")?,
+                columns: array(&json, "columns")?
+                    .iter()
+                    .map(Column::from_json)
+                    .collect::<Result<_, _>>()?,
+            })
+        }
+    }
+
+    impl Column {
+        fn from_json(json: &Json) -> Result<Self, Error> {
+            let tp = string(json, "type")?;
+            let tp = match tp.parse() {
+                Ok(Type::Struct | Type::Custom) | Err(_) => {
+                    return Err(invalid(format!("unknown column type `{tp}`")));
+                }
+                Ok(tp) => tp,
+            };
+
+            let foreign_key = match json.get("foreign_key") {
+                Some(foreign_key) => Some(ForeignKey {
+                    table:   string(foreign_key, "table")?,
+                    columns: strings(foreign_key, "columns")?,
+                }),
+                None => None,
+            };
+
+            Ok(Self {
+                name: string(json, "name")?,
+                tp,
+                type_name: string(json, "type_name")?,
+                nullable: bool(json, "nullable")?,
+                primary_key: bool(json, "primary_key")?,
+                foreign_key,
+                variants: if json.get("variants").is_some() {
+                    strings(json, "variants")?
+                } else {
+                    vec![]
+                },
+            })
+        }
+    }
+
+    impl Serialize for Schema {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut state = serializer.serialize_struct("Schema", 2)?;
+            state.serialize_field("name", &self.name)?;
+            state.serialize_field("columns", &self.columns)?;
+            state.end()
+        }
+    }
+
+    /// `foreign_key` and `variants` are omitted when empty
+    impl Serialize for Column {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let len = 5 + usize::from(self.foreign_key.is_some()) + usize::from(!self.variants.is_empty());
+            let mut state = serializer.serialize_struct("Column", len)?;
+            state.serialize_field("name", &self.name)?;
+            state.serialize_field("type", self.tp.as_str())?;
+            state.serialize_field("type_name", &self.type_name)?;
+            state.serialize_field("nullable", &self.nullable)?;
+            state.serialize_field("primary_key", &self.primary_key)?;
+            if let Some(foreign_key) = &self.foreign_key {
+                state.serialize_field("foreign_key", foreign_key)?;
+            }
+            if !self.variants.is_empty() {
+                state.serialize_field("variants", &self.variants)?;
+            }
+            state.end()
+        }
+    }
+
+    impl Serialize for ForeignKey {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut state = serializer.serialize_struct("ForeignKey", 2)?;
+            state.serialize_field("table", &self.table)?;
+            state.serialize_field("columns", &self.columns)?;
+            state.end()
+        }
+    }
+
+    fn invalid(error: impl ToString) -> Error {
+        Error::InvalidSnapshot {
+            error: error.to_string(),
+        }
+    }
+
+    fn field<'a>(json: &'a Json, key: &str) -> Result<&'a Json, Error> {
+        json.get(key).ok_or_else(|| invalid(format!("missing `{key}`")))
+    }
+
+    fn string(json: &Json, key: &str) -> Result<String, Error> {
+        field(json, key)?
+            .as_str()
+            .map(ToString::to_string)
+            .ok_or_else(|| invalid(format!("`{key}` must be a string")))
+    }
+
+    fn bool(json: &Json, key: &str) -> Result<bool, Error> {
+        field(json, key)?
+            .as_bool()
+            .ok_or_else(|| invalid(format!("`{key}` must be a bool")))
+    }
+
+    fn array<'a>(json: &'a Json, key: &str) -> Result<&'a [Json], Error> {
+        field(json, key)?
+            .as_array()
+            .map(Vec::as_slice)
+            .ok_or_else(|| invalid(format!("`{key}` must be an array")))
+    }
+
+    fn strings(json: &Json, key: &str) -> Result<Vec<String>, Error> {
+        array(json, key)?
+            .iter()
+            .map(|a| {
+                a.as_str()
+                    .map(ToString::to_string)
+                    .ok_or_else(|| invalid(format!("`{key}` must contain strings")))
+            })
+            .collect()
+    }
+}
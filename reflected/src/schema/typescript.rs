@@ -0,0 +1,120 @@
+use std::{
+    fmt::{Display, Formatter},
+    io,
+    path::Path,
+};
+
+use crate::{
+    schema::{
+        definition::{enums, Definition},
+        Property,
+    },
+    Reflected, Type,
+};
+
+/// TypeScript declarations of `Reflected` types
+///
+/// Every added type becomes an `interface` together with its nested structs.
+/// Enums become string literal unions, dates and decimals are `string`
+/// aliases matching `serde::AsSerde` output. Properties are in `fields()`
+/// order.
+///
+/// ```ignore
+/// TypeScript::new().add::<User>().add::<Order>().write_to("web/src/models.ts")?;
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TypeScript {
+    definitions: Vec<Definition>,
+}
+
+impl TypeScript {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Types already added as nested structs are not repeated
+    pub fn add<T: Reflected>(mut self) -> Self {
+        for definition in Definition::all::<T>() {
+            if !self.definitions.iter().any(|a| a.name == definition.name) {
+                self.definitions.push(definition);
+            }
+        }
+        self
+    }
+
+    /// File is left untouched if contents didn't change so build scripts
+    /// don't trigger rebuilds
+    pub fn write_to(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let contents = self.to_string();
+
+        if std::fs::read_to_string(path).is_ok_and(|existing| existing == contents) {
+            return Ok(());
+        }
+
+        std::fs::write(path, contents)
+    }
+
+    fn properties(&self) -> impl Iterator<Item = &Property> {
+        self.definitions.iter().flat_map(|a| &a.properties)
+    }
+}
+
+impl Display for TypeScript {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "// Generated from Reflected types, do not edit")?;
+
+        if self.properties().any(|a| a.tp.is_decimal()) {
+            writeln!(f, "\nexport type Decimal = string;")?;
+        }
+
+        if self.properties().any(|a| a.tp.is_date()) {
+            writeln!(f, "\n/** ISO 8601 without offset, UTC */")?;
+            writeln!(f, "export type DateTime = string;")?;
+        }
+
+        for property in enums(&self.definitions) {
+            let variants: Vec<_> = property.variants.iter().map(|a| format!("{a:?}")).collect();
+            writeln!(
+                f,
+                "\nexport type {} = {};",
+                property.type_name,
+                variants.join(" | ")
+            )?;
+        }
+
+        for definition in &self.definitions {
+            writeln!(f, "\nexport interface {} {{", definition.name)?;
+
+            for property in &definition.properties {
+                let tp = property_type(property);
+
+                if property.optional {
+                    writeln!(f, "  {}: {tp} | null;", property.name)?;
+                } else {
+                    writeln!(f, "  {}: {tp};", property.name)?;
+                }
+            }
+
+            writeln!(f, "}}")?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn typescript<T: Reflected>() -> String {
+    TypeScript::new().add::<T>().to_string()
+}
+
+fn property_type(property: &Property) -> &str {
+    match property.tp {
+        Type::Float | Type::Integer => "number",
+        Type::Text => "string",
+        Type::Bool => "boolean",
+        Type::Date => "DateTime",
+        Type::Decimal => "Decimal",
+        Type::Enum | Type::Struct => &property.type_name,
+        Type::Custom | Type::Optional(_) => unreachable!("Not a property type: {:?}", property.tp),
+    }
+}
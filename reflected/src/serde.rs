@@ -0,0 +1,269 @@
+//! `Serialize`/`Deserialize` for any `Reflected` type, enabled with `serde`
+//! feature
+//!
+//! Values are written according to `Field::tp`: numbers, bools and nulls as
+//! native types, dates as ISO-8601 strings and decimals as strings to keep
+//! precision. Nested structs are written as nested objects.
+//!
+//! Can be used directly with `#[serde(with = "reflected::serde")]` or through
+//! `AsSerde` wrapper.
+
+use std::{
+    fmt::Formatter,
+    ops::{Deref, DerefMut},
+};
+
+use ::serde::{
+    de::{self, DeserializeSeed, IgnoredAny, MapAccess, Visitor},
+    ser::{self, SerializeStruct},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use chrono::NaiveDateTime;
+
+use crate::{DynReflected, Reflected, Value};
+
+const DATE_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AsSerde<T>(pub T);
+
+/// Serialization only wrapper which doesn't take ownership
+#[derive(Debug, Clone, Copy)]
+pub struct AsSerdeRef<'a, T>(pub &'a T);
+
+impl<T> Deref for AsSerde<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for AsSerde<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: Reflected> Serialize for AsSerde<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(&self.0, serializer)
+    }
+}
+
+impl<T: Reflected> Serialize for AsSerdeRef<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self.0, serializer)
+    }
+}
+
+impl<'de, T: Reflected> Deserialize<'de> for AsSerde<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(Self)
+    }
+}
+
+pub fn serialize<T: Reflected, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    Object(value).serialize(serializer)
+}
+
+/// Missing fields are left with default values, unknown fields are ignored
+pub fn deserialize<'de, T: Reflected, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    let mut value = T::default();
+    ObjectSeed(&mut value).deserialize(deserializer)?;
+    Ok(value)
+}
+
+struct Object<'a>(&'a dyn DynReflected);
+
+impl Serialize for Object<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let fields = self
+            .0
+            .dyn_fields()
+            .into_iter()
+            .filter(|(_, tp)| !tp.is_custom())
+            .collect::<Vec<_>>();
+
+        let mut object = serializer.serialize_struct(self.0.dyn_type_name(), fields.len())?;
+
+        for (name, tp) in fields {
+            if tp.is_struct() {
+                match self.0.dyn_nested(name).map_err(ser::Error::custom)? {
+                    Some(nested) => object.serialize_field(name, &Object(nested))?,
+                    None => object.serialize_field(name, &None::<()>)?,
+                }
+                continue;
+            }
+
+            let value = self.0.dyn_get_val(name).map_err(ser::Error::custom)?;
+            object.serialize_field(name, &Scalar(value))?;
+        }
+
+        object.end()
+    }
+}
+
+struct Scalar(Value);
+
+impl Serialize for Scalar {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.0 {
+            Value::Null => serializer.serialize_none(),
+            Value::Int(int) => match i64::try_from(*int) {
+                Ok(int) => serializer.serialize_i64(int),
+                Err(_) => serializer.serialize_i128(*int),
+            },
+            Value::UInt(int) => match u64::try_from(*int) {
+                Ok(int) => serializer.serialize_u64(int),
+                Err(_) => serializer.serialize_u128(*int),
+            },
+            Value::Float(float) => serializer.serialize_f64(*float),
+            Value::Text(text) => serializer.serialize_str(text),
+            Value::Bool(bool) => serializer.serialize_bool(*bool),
+            Value::Date(date) => serializer.collect_str(&date.format(DATE_FORMAT)),
+            Value::Decimal(decimal) => serializer.collect_str(decimal),
+        }
+    }
+}
+
+struct ObjectSeed<'a>(&'a mut dyn DynReflected);
+
+impl<'de> DeserializeSeed<'de> for ObjectSeed<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ObjectSeed<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{} object", self.0.dyn_type_name())
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let fields = self.0.dyn_fields();
+
+        while let Some(key) = map.next_key::<String>()? {
+            let Some((name, tp)) = fields.iter().find(|(name, _)| *name == key) else {
+                map.next_value::<IgnoredAny>()?;
+                continue;
+            };
+
+            if tp.is_struct() {
+                map.next_value_seed(NestedSeed(self.0, name))?;
+                continue;
+            }
+
+            let value = match map.next_value::<ScalarValue>()?.0 {
+                Value::Text(text) if tp.is_date() => {
+                    NaiveDateTime::parse_from_str(text.trim_end_matches('Z'), DATE_FORMAT)
+                        .map(Value::Date)
+                        .map_err(|e| de::Error::custom(format!("Invalid date in `{name}`: {e}")))?
+                }
+                value => value,
+            };
+
+            self.0.dyn_set_val(name, value).map_err(de::Error::custom)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// `null` leaves optional nested struct unset
+struct NestedSeed<'a>(&'a mut dyn DynReflected, &'a str);
+
+impl<'de> DeserializeSeed<'de> for NestedSeed<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_option(self)
+    }
+}
+
+impl<'de> Visitor<'de> for NestedSeed<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "`{}` object or null", self.1)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        let nested = self.0.dyn_nested_mut(self.1).map_err(de::Error::custom)?;
+        ObjectSeed(nested).deserialize(deserializer)
+    }
+}
+
+/// Any scalar, converted to field type by `Reflected::set_val`
+struct ScalarValue(Value);
+
+impl<'de> Deserialize<'de> for ScalarValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ScalarVisitor)
+    }
+}
+
+struct ScalarVisitor;
+
+impl<'de> Visitor<'de> for ScalarVisitor {
+    type Value = ScalarValue;
+
+    fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.write_str("number, string, bool or null")
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<ScalarValue, E> {
+        Ok(ScalarValue(Value::Bool(value)))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<ScalarValue, E> {
+        Ok(ScalarValue(Value::Int(value.into())))
+    }
+
+    fn visit_i128<E: de::Error>(self, value: i128) -> Result<ScalarValue, E> {
+        Ok(ScalarValue(Value::Int(value)))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<ScalarValue, E> {
+        Ok(ScalarValue(Value::UInt(value.into())))
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<ScalarValue, E> {
+        Ok(ScalarValue(Value::UInt(value)))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<ScalarValue, E> {
+        Ok(ScalarValue(Value::Float(value)))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<ScalarValue, E> {
+        Ok(ScalarValue(Value::Text(value.to_string())))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<ScalarValue, E> {
+        Ok(ScalarValue(Value::Text(value)))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<ScalarValue, E> {
+        Ok(ScalarValue(Value::Null))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<ScalarValue, E> {
+        Ok(ScalarValue(Value::Null))
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<ScalarValue, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
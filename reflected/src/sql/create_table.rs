@@ -0,0 +1,99 @@
+use crate::{
+    schema::{Column, Schema},
+    sql::{dialect::quote_literal, Dialect},
+    Reflected,
+};
+
+/// `CREATE TABLE` named after `T::type_name()`
+///
+/// - `NOT NULL` for non optional fields
+/// - `PRIMARY KEY` from `#[reflected(id)]` fields
+/// - `FOREIGN KEY` from foreign key fields, see `Column::from_field`
+/// - enum fields are limited to their variants
+pub fn create_table<T: Reflected>(dialect: Dialect) -> String {
+    create_table_sql(dialect, &Schema::of::<T>(), false)
+}
+
+pub fn create_table_if_not_exists<T: Reflected>(dialect: Dialect) -> String {
+    create_table_sql(dialect, &Schema::of::<T>(), true)
+}
+
+pub(crate) fn create_table_sql(dialect: Dialect, schema: &Schema, if_not_exists: bool) -> String {
+    let mut lines = schema
+        .columns
+        .iter()
+        .map(|column| column_definition(dialect, &schema.name, column))
+        .collect::<Vec<_>>();
+
+    let primary_key = schema.primary_key();
+
+    if !primary_key.is_empty() {
+        lines.push(format!("PRIMARY KEY ({})", quote_list(dialect, &primary_key)));
+    }
+
+    for column in &schema.columns {
+        if let Some(foreign_key) = &column.foreign_key {
+            lines.push(format!(
+                "FOREIGN KEY ({}) REFERENCES {} ({})",
+                dialect.quote(&column.name),
+                dialect.quote(&foreign_key.table),
+                quote_list(dialect, &foreign_key.columns),
+            ));
+        }
+    }
+
+    format!(
+        "CREATE TABLE {}{} (\n    {}\n);",
+        if if_not_exists { "IF NOT EXISTS " } else { "" },
+        dialect.quote(&schema.name),
+        lines.join(",\n    ")
+    )
+}
+
+/// `"name" TYPE NOT NULL CONSTRAINT ... CHECK (...)` without key constraints
+pub(crate) fn column_definition(dialect: Dialect, table: &str, column: &Column) -> String {
+    let mut res = format!(
+        "{} {}",
+        dialect.quote(&column.name),
+        dialect.column_sql_type(column)
+    );
+
+    if !column.nullable {
+        res.push_str(" NOT NULL");
+    }
+
+    if let Some(check) = check_constraint(dialect, table, column) {
+        res.push(' ');
+        res.push_str(&check);
+    }
+
+    res
+}
+
+/// `CONSTRAINT "table_column_check" CHECK (...)` limiting enum columns to
+/// their variants, `Dialect::MySql` doesn't get one
+///
+/// Same name as Postgres gives unnamed column checks, so migrations can
+/// drop it when variants change.
+pub(crate) fn check_constraint(dialect: Dialect, table: &str, column: &Column) -> Option<String> {
+    if !column.tp.is_enum() || dialect == Dialect::MySql {
+        return None;
+    }
+
+    let variants = column.variants.iter().map(|a| quote_literal(a)).collect::<Vec<_>>();
+
+    Some(format!(
+        "CONSTRAINT {} CHECK ({} IN ({}))",
+        dialect.quote(&check_name(table, column)),
+        dialect.quote(&column.name),
+        variants.join(", ")
+    ))
+}
+
+pub(crate) fn check_name(table: &str, column: &Column) -> String {
+    format!("{table}_{}_check", column.name)
+}
+
+pub(crate) fn quote_list(dialect: Dialect, names: &[impl AsRef<str>]) -> String {
+    names.iter().map(|a| dialect.quote(a.as_ref())).collect::<Vec<_>>().join(", ")
+}
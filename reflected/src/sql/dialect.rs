@@ -0,0 +1,92 @@
+use crate::{schema::Column, Field, Type};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dialect {
+    Sqlite,
+    Postgres,
+    MySql,
+}
+
+impl Dialect {
+    /// Quotes table or column name
+    pub fn quote(&self, name: &str) -> String {
+        match self {
+            Self::Sqlite | Self::Postgres => format!("\"{}\"", name.replace('"', "\"\"")),
+            Self::MySql => format!("`{}`", name.replace('`', "``")),
+        }
+    }
+
+    /// `$1` for Postgres and `?` for others, `index` starts at 1
+    pub fn placeholder(&self, index: usize) -> String {
+        match self {
+            Self::Postgres => format!("${index}"),
+            Self::Sqlite | Self::MySql => "?".to_string(),
+        }
+    }
+
+    /// `None` for nested structs and custom fields
+    pub fn column_type<T>(&self, field: &Field<T>) -> Option<String> {
+        Column::from_field(field).map(|column| self.column_sql_type(&column))
+    }
+
+    /// Arms are grouped by dialect, so equal types repeat
+    #[allow(clippy::match_same_arms)]
+    pub fn column_sql_type(&self, column: &Column) -> String {
+        let is_key = column.primary_key || column.foreign_key.is_some();
+        let type_name = column.type_name.as_str();
+
+        let res = match (self, column.tp) {
+            (_, Type::Struct | Type::Custom | Type::Optional(_)) => unreachable!("not a column type"),
+
+            (Self::Sqlite, Type::Integer | Type::Bool) => "INTEGER",
+            (Self::Sqlite, Type::Float) => "REAL",
+            // `NUMERIC` affinity would store decimals as `REAL` and round them
+            (Self::Sqlite, Type::Text | Type::Enum | Type::Date | Type::Decimal) => "TEXT",
+
+            (Self::Postgres, Type::Integer) => match type_name {
+                "i8" | "u8" | "i16" => "SMALLINT",
+                "u16" | "i32" => "INTEGER",
+                "i128" | "u128" => "NUMERIC(39, 0)",
+                _ => "BIGINT",
+            },
+            (Self::Postgres, Type::Float) if type_name == "f32" => "REAL",
+            (Self::Postgres, Type::Float) => "DOUBLE PRECISION",
+            (Self::Postgres, Type::Text) if type_name == "char" => "CHAR(1)",
+            (Self::Postgres, Type::Text | Type::Enum) => "TEXT",
+            (Self::Postgres, Type::Date) => "TIMESTAMP",
+            (Self::Postgres, Type::Decimal) => "NUMERIC",
+            (Self::Postgres, Type::Bool) => "BOOLEAN",
+
+            (Self::MySql, Type::Integer) => match type_name {
+                "i8" => "TINYINT",
+                "u8" => "TINYINT UNSIGNED",
+                "i16" => "SMALLINT",
+                "u16" => "SMALLINT UNSIGNED",
+                "i32" => "INT",
+                "u32" => "INT UNSIGNED",
+                "u64" | "usize" => "BIGINT UNSIGNED",
+                "i128" | "u128" => "DECIMAL(39, 0)",
+                _ => "BIGINT",
+            },
+            (Self::MySql, Type::Float) if type_name == "f32" => "FLOAT",
+            (Self::MySql, Type::Float) => "DOUBLE",
+            (Self::MySql, Type::Text) if type_name == "char" => "CHAR(1)",
+            // MySQL can't index `TEXT` without prefix length
+            (Self::MySql, Type::Text) if is_key => "VARCHAR(255)",
+            (Self::MySql, Type::Text) => "TEXT",
+            (Self::MySql, Type::Enum) => {
+                let variants = column.variants.iter().map(|a| quote_literal(a)).collect::<Vec<_>>();
+                return format!("ENUM({})", variants.join(", "));
+            }
+            (Self::MySql, Type::Date) => "DATETIME(6)",
+            (Self::MySql, Type::Decimal) => "DECIMAL(38, 10)",
+            (Self::MySql, Type::Bool) => "BOOLEAN",
+        };
+
+        res.to_string()
+    }
+}
+
+pub(crate) fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
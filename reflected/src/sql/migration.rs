@@ -0,0 +1,186 @@
+use crate::{
+    schema::{Column, Migration, MigrationStep, Schema},
+    sql::{
+        create_table::{check_constraint, check_name, column_definition, create_table_sql, quote_list},
+        dialect::quote_literal,
+        Dialect,
+    },
+    Type,
+};
+
+impl Migration {
+    /// Statements to run in order
+    ///
+    /// Sqlite can't alter columns, so changes other than renaming the table,
+    /// adding plain columns and dropping non key columns rebuild the table.
+    /// Run it with foreign keys disabled.
+    ///
+    /// Enum check constraints are dropped and added again when variants
+    /// change.
+    ///
+    /// Columns which become `NOT NULL` are filled with a default value:
+    /// zero, empty string, `1970-01-01 00:00:00` or the first enum variant.
+    pub fn to_sql(&self, dialect: Dialect) -> Vec<String> {
+        if dialect == Dialect::Sqlite && self.steps.iter().any(needs_rebuild) {
+            return self.rebuild();
+        }
+
+        let table = dialect.quote(&self.to.name);
+        let mut res = vec![];
+
+        for step in &self.steps {
+            match step {
+                MigrationStep::RenameTable { from, to } => res.push(if dialect == Dialect::MySql {
+                    format!("RENAME TABLE {} TO {}", dialect.quote(from), dialect.quote(to))
+                } else {
+                    format!(
+                        "ALTER TABLE {} RENAME TO {}",
+                        dialect.quote(from),
+                        dialect.quote(to)
+                    )
+                }),
+                MigrationStep::AddColumn(column) => {
+                    let mut add = format!(
+                        "ALTER TABLE {table} ADD COLUMN {}",
+                        column_definition(dialect, &self.to.name, column)
+                    );
+                    if !column.nullable {
+                        add.push_str(&format!(" DEFAULT {}", default_value(dialect, column)));
+                    }
+                    res.push(add);
+
+                    if let (Some(foreign_key), false) = (&column.foreign_key, dialect == Dialect::Sqlite) {
+                        res.push(format!(
+                            "ALTER TABLE {table} ADD FOREIGN KEY ({}) REFERENCES {} ({})",
+                            dialect.quote(&column.name),
+                            dialect.quote(&foreign_key.table),
+                            quote_list(dialect, &foreign_key.columns)
+                        ));
+                    }
+                }
+                MigrationStep::DropColumn(column) => {
+                    res.push(format!(
+                        "ALTER TABLE {table} DROP COLUMN {}",
+                        dialect.quote(&column.name)
+                    ));
+                }
+                MigrationStep::ChangeNullable(column) => {
+                    let name = dialect.quote(&column.name);
+
+                    if !column.nullable {
+                        res.push(format!(
+                            "UPDATE {table} SET {name} = {} WHERE {name} IS NULL",
+                            default_value(dialect, column)
+                        ));
+                    }
+
+                    res.push(match (dialect, column.nullable) {
+                        (Dialect::MySql, _) => {
+                            format!(
+                                "ALTER TABLE {table} MODIFY COLUMN {}",
+                                column_definition(dialect, &self.to.name, column)
+                            )
+                        }
+                        (_, true) => format!("ALTER TABLE {table} ALTER COLUMN {name} DROP NOT NULL"),
+                        (_, false) => format!("ALTER TABLE {table} ALTER COLUMN {name} SET NOT NULL"),
+                    });
+                }
+                MigrationStep::ChangeType(column) => {
+                    let name = dialect.quote(&column.name);
+                    let old_enum = self.from.column(&column.name).filter(|a| a.tp.is_enum());
+
+                    if let (Some(old), false) = (old_enum, dialect == Dialect::MySql) {
+                        res.push(format!(
+                            "ALTER TABLE {table} DROP CONSTRAINT IF EXISTS {}",
+                            dialect.quote(&check_name(&self.from.name, old))
+                        ));
+                    }
+
+                    res.push(if dialect == Dialect::MySql {
+                        format!(
+                            "ALTER TABLE {table} MODIFY COLUMN {}",
+                            column_definition(dialect, &self.to.name, column)
+                        )
+                    } else {
+                        let tp = dialect.column_sql_type(column);
+                        format!("ALTER TABLE {table} ALTER COLUMN {name} TYPE {tp} USING {name}::{tp}")
+                    });
+
+                    if let Some(check) = check_constraint(dialect, &self.to.name, column) {
+                        res.push(format!("ALTER TABLE {table} ADD {check}"));
+                    }
+                }
+            }
+        }
+
+        res
+    }
+
+    /// Copy of `to` filled from `from` replaces the old table,
+    /// new `NOT NULL` columns are filled with defaults
+    fn rebuild(&self) -> Vec<String> {
+        let dialect = Dialect::Sqlite;
+
+        let new = Schema {
+            name: format!("{}__new", self.to.name),
+            ..self.to.clone()
+        };
+
+        let (columns, values): (Vec<_>, Vec<_>) = self
+            .to
+            .columns
+            .iter()
+            .filter_map(|column| {
+                let name = dialect.quote(&column.name);
+
+                let value = match self.from.column(&column.name) {
+                    None if column.nullable => return None,
+                    None => default_value(dialect, column),
+                    Some(old) if old.nullable && !column.nullable => {
+                        format!("COALESCE({name}, {})", default_value(dialect, column))
+                    }
+                    Some(_) => name.clone(),
+                };
+
+                Some((name, value))
+            })
+            .unzip();
+
+        vec![
+            create_table_sql(dialect, &new, false),
+            format!(
+                "INSERT INTO {} ({}) SELECT {} FROM {}",
+                dialect.quote(&new.name),
+                columns.join(", "),
+                values.join(", "),
+                dialect.quote(&self.from.name)
+            ),
+            format!("DROP TABLE {}", dialect.quote(&self.from.name)),
+            format!(
+                "ALTER TABLE {} RENAME TO {}",
+                dialect.quote(&new.name),
+                dialect.quote(&self.to.name)
+            ),
+        ]
+    }
+}
+
+fn needs_rebuild(step: &MigrationStep) -> bool {
+    match step {
+        MigrationStep::RenameTable { .. } => false,
+        MigrationStep::AddColumn(column) | MigrationStep::DropColumn(column) => {
+            column.primary_key || column.foreign_key.is_some()
+        }
+        MigrationStep::ChangeType(_) | MigrationStep::ChangeNullable(_) => true,
+    }
+}
+
+fn default_value(dialect: Dialect, column: &Column) -> String {
+    match column.tp {
+        Type::Bool if dialect != Dialect::Sqlite => "FALSE".to_string(),
+        Type::Text => "''".to_string(),
+        Type::Date => "'1970-01-01 00:00:00'".to_string(),
+        Type::Enum => quote_literal(column.variants.first().map_or("", String::as_str)),
+        _ => "0".to_string(),
+    }
+}
@@ -0,0 +1,15 @@
+//! SQL generation from `Reflected` types
+//!
+//! Tables are described with `schema::Schema`, nested structs and custom
+//! fields are not stored in columns and are skipped.
+
+mod create_table;
+mod dialect;
+mod migration;
+mod query;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+
+pub use create_table::{create_table, create_table_if_not_exists};
+pub use dialect::Dialect;
+pub use query::{delete, delete_by, insert, insert_batch, insert_simple, select, select_by, update, Query};
@@ -0,0 +1,216 @@
+use crate::{sql::Dialect, Error, Field, Reflected, Value};
+
+/// Statement with placeholders and values to bind to them in order
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    pub sql:    String,
+    pub params: Vec<Value>,
+}
+
+struct Builder {
+    dialect: Dialect,
+    sql:     String,
+    params:  Vec<Value>,
+}
+
+impl Builder {
+    fn new(dialect: Dialect) -> Self {
+        Self {
+            dialect,
+            sql: String::new(),
+            params: vec![],
+        }
+    }
+
+    fn bind(&mut self, value: Value) -> String {
+        self.params.push(value);
+        self.dialect.placeholder(self.params.len())
+    }
+
+    /// `"a" = $1, "b" = $2`
+    fn assignments<T>(&mut self, fields: &[&Field<T>], values: Vec<Value>) -> String {
+        fields
+            .iter()
+            .zip(values)
+            .map(|(field, value)| format!("{} = {}", self.dialect.quote(field.name), self.bind(value)))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// `"a" = $1 AND "b" IS NULL`
+    fn condition<T>(&mut self, fields: &[&Field<T>], values: Vec<Value>) -> String {
+        fields
+            .iter()
+            .zip(values)
+            .map(|(field, value)| {
+                let column = self.dialect.quote(field.name);
+                if value.is_null() {
+                    format!("{column} IS NULL")
+                } else {
+                    format!("{column} = {}", self.bind(value))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" AND ")
+    }
+
+    fn build(self) -> Query {
+        Query {
+            sql:    self.sql,
+            params: self.params,
+        }
+    }
+}
+
+fn column_list<T>(dialect: Dialect, fields: &[&Field<T>]) -> String {
+    fields.iter().map(|a| dialect.quote(a.name)).collect::<Vec<_>>().join(", ")
+}
+
+fn primary_key<T: Reflected>() -> Result<Vec<&'static Field<'static, T>>, Error> {
+    let fields = T::primary_key_fields();
+    if fields.is_empty() {
+        return Err(Error::NoPrimaryKey {
+            type_name: T::type_name(),
+        });
+    }
+    Ok(fields)
+}
+
+fn insert_rows<T: Reflected>(
+    dialect: Dialect,
+    fields: &[&'static Field<'static, T>],
+    rows: &[T],
+) -> Result<Query, Error> {
+    if rows.is_empty() {
+        return Err(Error::EmptyBatch);
+    }
+
+    // `() VALUES ()` is MySQL only, others fill every column with defaults
+    if fields.is_empty() && dialect != Dialect::MySql {
+        if rows.len() > 1 {
+            return Err(Error::NothingToInsert {
+                type_name: T::type_name(),
+            });
+        }
+
+        return Ok(Query {
+            sql:    format!("INSERT INTO {} DEFAULT VALUES", dialect.quote(T::type_name())),
+            params: vec![],
+        });
+    }
+
+    let mut builder = Builder::new(dialect);
+
+    let rows = rows
+        .iter()
+        .map(|row| {
+            let values = fields.iter().map(|field| builder.bind(row.get_val(field))).collect::<Vec<_>>();
+            format!("({})", values.join(", "))
+        })
+        .collect::<Vec<_>>();
+
+    builder.sql = format!(
+        "INSERT INTO {} ({}) VALUES {}",
+        dialect.quote(T::type_name()),
+        column_list(dialect, fields),
+        rows.join(", ")
+    );
+
+    Ok(builder.build())
+}
+
+/// Inserts every column including keys
+pub fn insert<T: Reflected>(dialect: Dialect, value: &T) -> Query {
+    insert_rows(dialect, &T::column_fields(), std::slice::from_ref(value)).unwrap()
+}
+
+/// Inserts only `simple_fields()`, for tables with generated primary key
+///
+/// `DEFAULT VALUES` if there are none
+pub fn insert_simple<T: Reflected>(dialect: Dialect, value: &T) -> Query {
+    insert_rows(dialect, T::simple_fields(), std::slice::from_ref(value)).unwrap()
+}
+
+/// Single `INSERT` with a row per value
+///
+/// `Error::NothingToInsert` if there are several values but no columns,
+/// except for `Dialect::MySql`
+pub fn insert_batch<T: Reflected>(dialect: Dialect, values: &[T]) -> Result<Query, Error> {
+    insert_rows(dialect, &T::column_fields(), values)
+}
+
+/// Updates every column except primary key, matched by primary key
+///
+/// `Error::NothingToUpdate` if every column is a part of the primary key
+pub fn update<T: Reflected>(dialect: Dialect, value: &T) -> Result<Query, Error> {
+    let key = primary_key::<T>()?;
+    let fields = T::column_fields().into_iter().filter(|a| !a.is_id()).collect::<Vec<_>>();
+
+    if fields.is_empty() {
+        return Err(Error::NothingToUpdate {
+            type_name: T::type_name(),
+        });
+    }
+
+    let mut builder = Builder::new(dialect);
+
+    let set = builder.assignments(&fields, fields.iter().map(|a| value.get_val(a)).collect());
+    let condition = builder.condition(&key, key.iter().map(|a| value.get_val(a)).collect());
+
+    builder.sql = format!(
+        "UPDATE {} SET {set} WHERE {condition}",
+        dialect.quote(T::type_name())
+    );
+
+    Ok(builder.build())
+}
+
+pub fn select<T: Reflected>(dialect: Dialect) -> Query {
+    Query {
+        sql:    format!(
+            "SELECT {} FROM {}",
+            column_list(dialect, &T::column_fields()),
+            dialect.quote(T::type_name())
+        ),
+        params: vec![],
+    }
+}
+
+/// `NULL` value selects rows where field `IS NULL`
+pub fn select_by<T: Reflected>(
+    dialect: Dialect,
+    field: &'static Field<'static, T>,
+    value: impl Into<Value>,
+) -> Query {
+    let mut builder = Builder::new(dialect);
+    let condition = builder.condition(&[field], vec![value.into()]);
+
+    builder.sql = format!("{} WHERE {condition}", select::<T>(dialect).sql);
+
+    builder.build()
+}
+
+/// Deletes row matched by primary key
+pub fn delete<T: Reflected>(dialect: Dialect, value: &T) -> Result<Query, Error> {
+    let key = primary_key::<T>()?;
+
+    let mut builder = Builder::new(dialect);
+    let condition = builder.condition(&key, key.iter().map(|a| value.get_val(a)).collect());
+
+    builder.sql = format!("DELETE FROM {} WHERE {condition}", dialect.quote(T::type_name()));
+
+    Ok(builder.build())
+}
+
+pub fn delete_by<T: Reflected>(
+    dialect: Dialect,
+    field: &'static Field<'static, T>,
+    value: impl Into<Value>,
+) -> Query {
+    let mut builder = Builder::new(dialect);
+    let condition = builder.condition(&[field], vec![value.into()]);
+
+    builder.sql = format!("DELETE FROM {} WHERE {condition}", dialect.quote(T::type_name()));
+
+    builder.build()
+}
@@ -0,0 +1,167 @@
+//! Repository over `rusqlite`, enabled with `sqlite` feature
+
+use std::fmt::{Display, Formatter};
+
+pub use rusqlite;
+use rusqlite::{
+    params_from_iter,
+    types::{ToSqlOutput, ValueRef},
+    Connection, ToSql,
+};
+
+use crate::{
+    sql::{self, Dialect, Query},
+    Field, Reflected, Value,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    Sqlite(rusqlite::Error),
+    Reflected(crate::Error),
+}
+
+impl From<rusqlite::Error> for Error {
+    fn from(error: rusqlite::Error) -> Self {
+        Self::Sqlite(error)
+    }
+}
+
+impl From<crate::Error> for Error {
+    fn from(error: crate::Error) -> Self {
+        Self::Reflected(error)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Sqlite(error) => write!(f, "SQLite error: {error}"),
+            Self::Reflected(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// SQLite integers are 64 bit, larger values fail to convert
+impl ToSql for Value {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        use rusqlite::types::Value as Sql;
+
+        let out_of_range = |e| rusqlite::Error::ToSqlConversionFailure(Box::new(e));
+
+        Ok(ToSqlOutput::Owned(match self {
+            Value::Null => Sql::Null,
+            Value::Int(int) => Sql::Integer(i64::try_from(*int).map_err(out_of_range)?),
+            Value::UInt(int) => Sql::Integer(i64::try_from(*int).map_err(out_of_range)?),
+            Value::Float(float) => Sql::Real(*float),
+            Value::Bool(bool) => Sql::Integer((*bool).into()),
+            Value::Text(_) | Value::Date(_) | Value::Decimal(_) => Sql::Text(self.to_string()),
+        }))
+    }
+}
+
+pub struct Repository {
+    connection: Connection,
+}
+
+impl Repository {
+    pub fn new(connection: Connection) -> Self {
+        Self { connection }
+    }
+
+    pub fn open_in_memory() -> Result<Self> {
+        Ok(Self::new(Connection::open_in_memory()?))
+    }
+
+    pub fn connection(&self) -> &Connection {
+        &self.connection
+    }
+
+    pub fn create_table<T: Reflected>(&self) -> Result<()> {
+        self.connection
+            .execute(&sql::create_table_if_not_exists::<T>(Dialect::Sqlite), [])?;
+        Ok(())
+    }
+
+    pub fn insert<T: Reflected>(&self, value: &T) -> Result<()> {
+        self.execute(sql::insert(Dialect::Sqlite, value))?;
+        Ok(())
+    }
+
+    /// All rows are inserted in one transaction
+    pub fn insert_batch<T: Reflected>(&mut self, values: &[T]) -> Result<()> {
+        let transaction = self.connection.transaction()?;
+        for value in values {
+            let query = sql::insert(Dialect::Sqlite, value);
+            transaction.execute(&query.sql, params_from_iter(&query.params))?;
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+
+    pub fn select_all<T: Reflected>(&self) -> Result<Vec<T>> {
+        self.query(sql::select::<T>(Dialect::Sqlite))
+    }
+
+    pub fn find_by<T: Reflected>(
+        &self,
+        field: &'static Field<'static, T>,
+        value: impl Into<Value>,
+    ) -> Result<Vec<T>> {
+        self.query(sql::select_by(Dialect::Sqlite, field, value))
+    }
+
+    /// Returns number of updated rows
+    pub fn update<T: Reflected>(&self, value: &T) -> Result<usize> {
+        self.execute(sql::update(Dialect::Sqlite, value)?)
+    }
+
+    /// Returns number of deleted rows
+    pub fn delete<T: Reflected>(&self, value: &T) -> Result<usize> {
+        self.execute(sql::delete(Dialect::Sqlite, value)?)
+    }
+
+    fn execute(&self, query: Query) -> Result<usize> {
+        Ok(self.connection.execute(&query.sql, params_from_iter(&query.params))?)
+    }
+
+    /// Columns are expected in the order of `sql::select`
+    fn query<T: Reflected>(&self, query: Query) -> Result<Vec<T>> {
+        let fields = T::column_fields();
+
+        let mut statement = self.connection.prepare(&query.sql)?;
+        let mut rows = statement.query(params_from_iter(&query.params))?;
+
+        let mut res = vec![];
+
+        while let Some(row) = rows.next()? {
+            let mut value = T::default();
+
+            for (index, field) in fields.iter().enumerate() {
+                let column = match row.get_ref(index)? {
+                    ValueRef::Null => None,
+                    ValueRef::Integer(int) => Some(int.to_string()),
+                    ValueRef::Real(float) => Some(float.to_string()),
+                    ValueRef::Text(text) => Some(String::from_utf8_lossy(text).into_owned()),
+                    ValueRef::Blob(_) => {
+                        return Err(rusqlite::Error::InvalidColumnType(
+                            index,
+                            field.name.to_string(),
+                            rusqlite::types::Type::Blob,
+                        )
+                        .into())
+                    }
+                };
+
+                value.try_set_value(field, column.as_deref())?;
+            }
+
+            res.push(value);
+        }
+
+        Ok(res)
+    }
+}
@@ -1,30 +1,47 @@
+use chrono::NaiveDateTime;
 use rust_decimal::{prelude::Zero, Decimal};
 
+use crate::ReflectedEnum;
+
 pub trait ToReflectedString {
     fn to_reflected_string(&self) -> String;
 }
 
-impl ToReflectedString for Option<&str> {
-    fn to_reflected_string(&self) -> String {
-        self.unwrap_or("NULL").to_string()
-    }
+macro_rules! display_to_reflected_string {
+    ($($tp:ty),*) => {
+        $(
+            impl ToReflectedString for $tp {
+                fn to_reflected_string(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
 }
 
-impl ToReflectedString for Option<String> {
-    fn to_reflected_string(&self) -> String {
-        self.clone().unwrap_or("NULL".to_string())
-    }
-}
-
-impl ToReflectedString for Option<usize> {
-    fn to_reflected_string(&self) -> String {
-        self.map(|a| a.to_string()).unwrap_or("NULL".to_string())
-    }
-}
+display_to_reflected_string!(
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    char,
+    &str,
+    String,
+    Decimal,
+    NaiveDateTime
+);
 
-impl ToReflectedString for Option<Decimal> {
+impl ToReflectedString for bool {
     fn to_reflected_string(&self) -> String {
-        self.map(|a| a.to_string()).unwrap_or("NULL".to_string())
+        if *self { "1" } else { "0" }.to_string()
     }
 }
 
@@ -47,3 +64,15 @@ impl ToReflectedString for f32 {
         }
     }
 }
+
+impl<T: ReflectedEnum> ToReflectedString for T {
+    fn to_reflected_string(&self) -> String {
+        self.variant_name().to_string()
+    }
+}
+
+impl<T: ToReflectedString> ToReflectedString for Option<T> {
+    fn to_reflected_string(&self) -> String {
+        self.as_ref().map_or("NULL".to_string(), ToReflectedString::to_reflected_string)
+    }
+}
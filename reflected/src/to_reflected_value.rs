@@ -0,0 +1,77 @@
+use chrono::NaiveDateTime;
+use rust_decimal::Decimal;
+
+use crate::{ReflectedEnum, Value};
+
+pub trait ToReflectedValue {
+    fn to_reflected_value(&self) -> Value;
+}
+
+macro_rules! to_reflected_value {
+    ($variant:ident, $($tp:ty),*) => {
+        $(
+            impl ToReflectedValue for $tp {
+                fn to_reflected_value(&self) -> Value {
+                    Value::$variant((*self).into())
+                }
+            }
+        )*
+    };
+}
+
+to_reflected_value!(Int, i8, i16, i32, i64, i128);
+to_reflected_value!(UInt, u8, u16, u32, u64, u128);
+to_reflected_value!(Float, f64);
+to_reflected_value!(Bool, bool);
+to_reflected_value!(Date, NaiveDateTime);
+to_reflected_value!(Decimal, Decimal);
+
+impl ToReflectedValue for isize {
+    fn to_reflected_value(&self) -> Value {
+        Value::Int(*self as i128)
+    }
+}
+
+impl ToReflectedValue for usize {
+    fn to_reflected_value(&self) -> Value {
+        Value::UInt(*self as u128)
+    }
+}
+
+impl ToReflectedValue for f32 {
+    fn to_reflected_value(&self) -> Value {
+        // Shortest decimal representation keeps `0.1f32` as `0.1` instead of
+        // `0.10000000149011612`
+        Value::Float(self.to_string().parse().unwrap_or(f64::from(*self)))
+    }
+}
+
+impl ToReflectedValue for char {
+    fn to_reflected_value(&self) -> Value {
+        Value::Text(self.to_string())
+    }
+}
+
+impl ToReflectedValue for &str {
+    fn to_reflected_value(&self) -> Value {
+        Value::Text((*self).to_string())
+    }
+}
+
+impl ToReflectedValue for String {
+    fn to_reflected_value(&self) -> Value {
+        Value::Text(self.clone())
+    }
+}
+
+impl<T: ReflectedEnum> ToReflectedValue for T {
+    fn to_reflected_value(&self) -> Value {
+        Value::Text(self.variant_name().to_string())
+    }
+}
+
+impl<T: ToReflectedValue> ToReflectedValue for Option<T> {
+    fn to_reflected_value(&self) -> Value {
+        self.as_ref().map_or(Value::Null, ToReflectedValue::to_reflected_value)
+    }
+}
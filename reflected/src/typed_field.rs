@@ -0,0 +1,41 @@
+use std::{
+    fmt::{Debug, Formatter},
+    ops::Deref,
+};
+
+use crate::Field;
+
+/// Field handle which knows the concrete value type
+/// and can access it directly without string conversions
+pub struct TypedField<T: 'static, V> {
+    pub field:      Field<'static, T>,
+    pub getter:     fn(&T) -> &V,
+    pub getter_mut: fn(&mut T) -> &mut V,
+}
+
+impl<T, V> TypedField<T, V> {
+    pub fn get<'a>(&self, obj: &'a T) -> &'a V {
+        (self.getter)(obj)
+    }
+
+    pub fn get_mut<'a>(&self, obj: &'a mut T) -> &'a mut V {
+        (self.getter_mut)(obj)
+    }
+
+    pub fn set(&self, obj: &mut T, value: V) {
+        *self.get_mut(obj) = value;
+    }
+}
+
+impl<T, V> Deref for TypedField<T, V> {
+    type Target = Field<'static, T>;
+    fn deref(&self) -> &Self::Target {
+        &self.field
+    }
+}
+
+impl<T, V> Debug for TypedField<T, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.field.fmt(f)
+    }
+}
@@ -0,0 +1,102 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::NaiveDateTime;
+use rust_decimal::Decimal;
+
+use crate::{ToReflectedString, ToReflectedValue};
+
+/// Typed value of a reflected field
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Int(i128),
+    UInt(u128),
+    Float(f64),
+    Text(String),
+    Bool(bool),
+    Date(NaiveDateTime),
+    Decimal(Decimal),
+}
+
+impl Value {
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Null => "Null",
+            Self::Int(_) => "Int",
+            Self::UInt(_) => "UInt",
+            Self::Float(_) => "Float",
+            Self::Text(_) => "Text",
+            Self::Bool(_) => "Bool",
+            Self::Date(_) => "Date",
+            Self::Decimal(_) => "Decimal",
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+}
+
+/// Same format as `ToReflectedString`: `NULL` for null and `0`/`1` for bools
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Null => f.write_str("NULL"),
+            Self::Int(value) => write!(f, "{value}"),
+            Self::UInt(value) => write!(f, "{value}"),
+            Self::Float(value) => f.write_str(&value.to_reflected_string()),
+            Self::Text(value) => f.write_str(value),
+            Self::Bool(value) => f.write_str(&value.to_reflected_string()),
+            Self::Date(value) => f.write_str(&value.to_reflected_string()),
+            Self::Decimal(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+macro_rules! value_from {
+    ($($tp:ty),*) => {
+        $(
+            impl From<$tp> for Value {
+                fn from(value: $tp) -> Self {
+                    value.to_reflected_value()
+                }
+            }
+        )*
+    };
+}
+
+value_from!(
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    f32,
+    f64,
+    char,
+    &str,
+    String,
+    bool,
+    NaiveDateTime,
+    Decimal
+);
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::Null, Into::into)
+    }
+}
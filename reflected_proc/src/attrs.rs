@@ -0,0 +1,299 @@
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    token, Attribute, Error, Expr, ExprRange, Ident, Lit, Meta, NestedMeta, Path, Result, Token,
+};
+
+use crate::{
+    field::{FieldKind, Keys},
+    rename_rule::RenameRule,
+};
+
+/// `#[reflected(...)]` on a struct or an enum
+#[derive(Default)]
+pub(crate) struct TypeAttrs {
+    pub(crate) rename:     Option<String>,
+    pub(crate) rename_all: Option<RenameRule>,
+    /// Treat `id` as primary key and `*_id` fields as foreign keys
+    pub(crate) infer_keys: bool,
+    /// Lenient parsing in `set_value` for every field
+    pub(crate) lenient:    bool,
+    /// `proptest` and `quickcheck` `Arbitrary` impls if features are enabled,
+    /// `arbitrary` ident for error spans, `None` if attribute is not set
+    pub(crate) arbitrary:  Option<Ident>,
+}
+
+/// `#[reflected(...)]` on a struct field
+#[derive(Default)]
+pub(crate) struct FieldAttrs {
+    pub(crate) rename:        Option<String>,
+    pub(crate) skip:          bool,
+    pub(crate) keys:          Keys,
+    pub(crate) references:    Option<Path>,
+    pub(crate) type_override: Option<Ident>,
+    pub(crate) kind:          FieldKind,
+    pub(crate) lenient:       bool,
+    pub(crate) tag:           Option<u32>,
+    pub(crate) random:        Option<RandomAttrs>,
+}
+
+/// `#[reflected(random(...))]` on a struct field
+#[derive(Default, Debug)]
+pub(crate) struct RandomAttrs {
+    /// `random` ident for error spans, `None` if attribute is not set
+    pub(crate) span:       Option<Ident>,
+    pub(crate) range:      Option<ExprRange>,
+    pub(crate) len:        Option<ExprRange>,
+    pub(crate) date_range: Option<ExprRange>,
+    pub(crate) with:       Option<Path>,
+}
+
+/// `#[reflected(...)]` on an enum variant
+#[derive(Default)]
+pub(crate) struct VariantAttrs {
+    pub(crate) rename: Option<String>,
+}
+
+impl TypeAttrs {
+    pub(crate) fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut res = Self::default();
+
+        for meta in reflected_metas(attrs)? {
+            match &meta {
+                NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("rename") => {
+                    res.rename = lit_str(&value.lit)?.into();
+                }
+                NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("rename_all") => {
+                    let rule = lit_str(&value.lit)?;
+                    res.rename_all = RenameRule::from_name(&rule)
+                        .ok_or_else(|| {
+                            Error::new_spanned(
+                                &value.lit,
+                                format!(
+                                    "unknown rename rule `{rule}`, expected one of: {}",
+                                    RenameRule::ALL.join(", ")
+                                ),
+                            )
+                        })?
+                        .into();
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("infer_keys") => res.infer_keys = true,
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("lenient") => res.lenient = true,
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("arbitrary") => {
+                    res.arbitrary = path.get_ident().cloned();
+                }
+                _ => {
+                    return Err(unknown(
+                        &meta,
+                        "`rename`, `rename_all`, `infer_keys`, `lenient`, `arbitrary`",
+                    ))
+                }
+            }
+        }
+
+        Ok(res)
+    }
+}
+
+impl FieldAttrs {
+    pub(crate) fn parse(field: &syn::Field) -> Result<Self> {
+        let mut res = Self::default();
+
+        for arg in reflected_args(&field.attrs)? {
+            let meta = match arg {
+                ReflectedArg::Meta(meta) => meta,
+                ReflectedArg::Random(random) => {
+                    if res.random.is_some() {
+                        return Err(Error::new_spanned(&random.span, "duplicate `random` attribute"));
+                    }
+                    res.random = random.into();
+                    continue;
+                }
+            };
+
+            match &meta {
+                NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("rename") => {
+                    res.rename = lit_str(&value.lit)?.into();
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") => res.skip = true,
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("id") => res.keys.primary = true,
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("foreign_key") => res.keys.foreign = true,
+                NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("foreign_key") => {
+                    let Lit::Str(lit) = &value.lit else {
+                        return Err(Error::new_spanned(
+                            &value.lit,
+                            "expected referenced type name string",
+                        ));
+                    };
+                    res.keys.foreign = true;
+                    res.references = lit.parse::<Path>()?.into();
+                }
+                NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("type") => {
+                    let Lit::Str(lit) = &value.lit else {
+                        return Err(Error::new_spanned(&value.lit, "expected type name string"));
+                    };
+                    res.type_override = lit.parse::<Ident>()?.into();
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("enum") => {
+                    res.set_kind(FieldKind::Enum, field)?;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("struct") => {
+                    res.set_kind(FieldKind::Struct, field)?;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("lenient") => res.lenient = true,
+                NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("tag") => {
+                    let Lit::Int(lit) = &value.lit else {
+                        return Err(Error::new_spanned(&value.lit, "expected field number"));
+                    };
+                    let tag = lit.base10_parse::<u32>()?;
+                    if tag == 0 {
+                        return Err(Error::new_spanned(lit, "field number must start from 1"));
+                    }
+                    res.tag = tag.into();
+                }
+                _ => {
+                    return Err(unknown(
+                        &meta,
+                        "`rename`, `skip`, `id`, `foreign_key`, `type`, `enum`, `struct`, `lenient`, `tag`, \
+                         `random`",
+                    ))
+                }
+            }
+        }
+
+        Ok(res)
+    }
+
+    fn set_kind(&mut self, kind: FieldKind, field: &syn::Field) -> Result<()> {
+        if self.kind != FieldKind::Value && self.kind != kind {
+            return Err(Error::new_spanned(
+                &field.ty,
+                "field can't be both `#[reflected(enum)]` and `#[reflected(struct)]`",
+            ));
+        }
+        self.kind = kind;
+        Ok(())
+    }
+}
+
+impl VariantAttrs {
+    pub(crate) fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut res = Self::default();
+
+        for meta in reflected_metas(attrs)? {
+            match &meta {
+                NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("rename") => {
+                    res.rename = lit_str(&value.lit)?.into();
+                }
+                _ => return Err(unknown(&meta, "`rename`")),
+            }
+        }
+
+        Ok(res)
+    }
+}
+
+impl Parse for RandomAttrs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut res = Self::default();
+
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+
+            match key.to_string().as_str() {
+                "range" => res.range = range(input)?.into(),
+                "len" => res.len = range(input)?.into(),
+                "date_range" => res.date_range = range(input)?.into(),
+                "with" => res.with = input.parse::<Path>()?.into(),
+                _ => {
+                    return Err(Error::new_spanned(
+                        key,
+                        "unknown `random` option, expected one of: `range`, `len`, `date_range`, `with`",
+                    ))
+                }
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(res)
+    }
+}
+
+/// `random(...)` takes ranges and paths which `Meta` can't hold
+enum ReflectedArg {
+    Meta(NestedMeta),
+    Random(RandomAttrs),
+}
+
+impl Parse for ReflectedArg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Ident) && input.peek2(token::Paren) && input.fork().parse::<Ident>()? == "random" {
+            let span: Ident = input.parse()?;
+            let content;
+            parenthesized!(content in input);
+            let mut random: RandomAttrs = content.parse()?;
+            random.span = span.into();
+            return Ok(Self::Random(random));
+        }
+
+        input.parse().map(Self::Meta)
+    }
+}
+
+fn reflected_args(attrs: &[Attribute]) -> Result<Vec<ReflectedArg>> {
+    let mut res = vec![];
+
+    for attr in attrs {
+        if !attr.path.is_ident("reflected") {
+            continue;
+        }
+
+        if let Ok(Meta::Path(_) | Meta::NameValue(_)) = attr.parse_meta() {
+            return Err(Error::new_spanned(attr, "expected `#[reflected(...)]`"));
+        }
+
+        res.extend(attr.parse_args_with(Punctuated::<ReflectedArg, Token![,]>::parse_terminated)?);
+    }
+
+    Ok(res)
+}
+
+fn reflected_metas(attrs: &[Attribute]) -> Result<Vec<NestedMeta>> {
+    reflected_args(attrs)?
+        .into_iter()
+        .map(|arg| match arg {
+            ReflectedArg::Meta(meta) => Ok(meta),
+            ReflectedArg::Random(random) => Err(Error::new_spanned(
+                random.span,
+                "`random` is only supported on struct fields",
+            )),
+        })
+        .collect()
+}
+
+/// `a..b` or `a..=b`
+fn range(input: ParseStream) -> Result<ExprRange> {
+    match input.parse::<Expr>()? {
+        Expr::Range(range) if range.from.is_some() && range.to.is_some() => Ok(range),
+        expr => Err(Error::new_spanned(expr, "expected `a..b` or `a..=b` range")),
+    }
+}
+
+fn lit_str(lit: &Lit) -> Result<String> {
+    let Lit::Str(lit) = lit else {
+        return Err(Error::new_spanned(lit, "expected string literal"));
+    };
+    Ok(lit.value())
+}
+
+fn unknown(meta: &NestedMeta, expected: &str) -> Error {
+    Error::new_spanned(
+        meta,
+        format!("unknown `reflected` attribute, expected one of: {expected}"),
+    )
+}
@@ -1,14 +1,47 @@
 use std::str::FromStr;
 
-use syn::{Ident, __private::Span};
+use syn::{Ident, Path, Type, __private::Span};
 
-use crate::TokenStream2;
+use crate::{attrs::RandomAttrs, TokenStream2};
+
+/// Set with `#[reflected(enum)]` or `#[reflected(struct)]`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FieldKind {
+    #[default]
+    Value,
+    Enum,
+    Struct,
+}
+
+/// Set with `#[reflected(id)]` and `#[reflected(foreign_key)]` or inferred
+/// from field names
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct Keys {
+    pub(crate) primary: bool,
+    pub(crate) foreign: bool,
+}
 
 #[derive(Debug)]
 pub(crate) struct Field {
-    pub(crate) name:     Ident,
-    pub(crate) tp:       Ident,
-    pub(crate) optional: bool,
+    pub(crate) name:           Ident,
+    /// Name after `rename` and `rename_all` attributes
+    pub(crate) reflected_name: String,
+    /// Last path segment of the field type without `Option`
+    pub(crate) tp:             Ident,
+    /// Set with `#[reflected(type = "u64")]` for type aliases
+    pub(crate) type_override:  Option<Ident>,
+    /// Full field type including `Option`
+    pub(crate) ty:             Type,
+    /// Field type without `Option`
+    pub(crate) base_ty:        Type,
+    pub(crate) optional:       bool,
+    pub(crate) kind:           FieldKind,
+    pub(crate) keys:           Keys,
+    pub(crate) references:     Option<Path>,
+    pub(crate) lenient:        bool,
+    /// `.proto` field number
+    pub(crate) tag:            u32,
+    pub(crate) random:         RandomAttrs,
 }
 
 impl Field {
@@ -17,35 +50,36 @@ impl Field {
     }
 
     pub(crate) fn name_as_string(&self) -> TokenStream2 {
-        TokenStream2::from_str(&format!("\"{}\"", self.name)).unwrap()
+        TokenStream2::from_str(&format!("{:?}", self.reflected_name)).unwrap()
     }
 
     pub(crate) fn id(&self) -> bool {
-        self.name == Ident::new("id", Span::call_site())
+        self.keys.primary
     }
 
     pub(crate) fn is_foreign_id(&self) -> bool {
-        self.name.to_string().contains("_id")
+        self.keys.foreign
     }
 
-    pub(crate) fn custom(&self) -> bool {
-        self.field_type() == Ident::new("Custom", Span::call_site())
+    pub(crate) fn is_enum(&self) -> bool {
+        self.kind == FieldKind::Enum
     }
 
-    pub(crate) fn is_simple(&self) -> bool {
-        !self.id() && !self.custom() && !self.is_foreign_id()
+    pub(crate) fn is_struct(&self) -> bool {
+        self.kind == FieldKind::Struct
     }
 
-    pub(crate) fn is_bool(&self) -> bool {
-        self.field_type() == "Bool"
+    /// `type_override` if set, last path segment of the field type otherwise
+    pub(crate) fn value_type(&self) -> &Ident {
+        self.type_override.as_ref().unwrap_or(&self.tp)
     }
 
-    pub(crate) fn is_float(&self) -> bool {
-        self.field_type() == "Float"
+    pub(crate) fn custom(&self) -> bool {
+        self.field_type() == Ident::new("Custom", Span::call_site())
     }
 
-    pub(crate) fn is_date(&self) -> bool {
-        self.field_type() == "Date"
+    pub(crate) fn is_simple(&self) -> bool {
+        !self.id() && !self.custom() && !self.is_struct() && !self.is_foreign_id()
     }
 
     pub(crate) fn field_type(&self) -> Ident {
@@ -57,10 +91,19 @@ impl Field {
         let decimal = Ident::new("Decimal", Span::call_site());
         let bool = Ident::new("Bool", Span::call_site());
 
-        match self.tp.to_string().as_str() {
+        if self.is_enum() {
+            return Ident::new("Enum", Span::call_site());
+        }
+
+        if self.is_struct() {
+            return Ident::new("Struct", Span::call_site());
+        }
+
+        match self.value_type().to_string().as_str() {
             "f32" | "f64" => float,
-            "i32" | "u32" | "i64" | "u64" | "isize" | "usize" => integer,
-            "String" => text,
+            "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "u128"
+            | "usize" => integer,
+            "String" | "char" => text,
             "NaiveDateTime" => date,
             "Decimal" => decimal,
             "bool" => bool,
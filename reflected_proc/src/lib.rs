@@ -3,49 +3,121 @@ use std::str::FromStr;
 use proc_macro::TokenStream;
 use quote::{quote, ToTokens};
 use syn::{
-    parse_macro_input, Attribute, Data, DeriveInput, Fields, FieldsNamed, GenericArgument, Ident, Meta,
-    NestedMeta, PathArguments, Type,
+    parse_macro_input, Data, DataStruct, DeriveInput, Error, Expr, ExprLit, ExprRange, ExprUnary, Fields,
+    FieldsNamed, GenericArgument, Ident, Lit, PathArguments, RangeLimits, Type, UnOp,
     __private::{Span, TokenStream2},
 };
 
-use crate::field::Field;
+use crate::{
+    attrs::{FieldAttrs, RandomAttrs, TypeAttrs},
+    field::{Field, Keys},
+    reflected_enum::reflected_enum,
+};
 
+mod attrs;
 mod field;
+mod reflected_enum;
+mod rename_rule;
 
 /// Data must also derive `Default`
-#[proc_macro_derive(Reflected)]
+/// Can also be used on fieldless enums
+///
+/// Struct and enum attributes `#[reflected(...)]`:
+/// - `rename = "name"`
+/// - `rename_all = "snake_case"`
+/// - `infer_keys` - `id` is a primary key and `*_id` are foreign keys
+/// - `lenient` - `lenient` for every field
+/// - `arbitrary` - `proptest::Arbitrary` and `quickcheck::Arbitrary` impls for
+///   structs when `proptest` and `quickcheck` features of `reflected` are
+///   enabled, `quickcheck` also needs `Clone`
+///
+/// Field attributes `#[reflected(...)]`:
+/// - `rename = "name"`
+/// - `skip`
+/// - `id`
+/// - `foreign_key` or `foreign_key = "Type"`
+/// - `type = "u64"` - reflect a type alias as the type it stands for
+/// - `enum` - field type derives `Reflected` as an enum
+/// - `struct` - field type derives `Reflected` as a struct
+/// - `lenient` - `set_value` accepts alternate spellings like `yes` or `42.0`
+/// - `random(...)` - value generated by `Reflected::random`, one of:
+///   - `range = 18..99` - numbers and decimals
+///   - `len = 3..20` - length of `String` fields
+///   - `date_range = "2020-01-01".."2025-01-01"` - dates
+///   - `with = path::to_fn` - `fn(&mut impl Rng) -> FieldType`, also for custom
+///     fields
+///
+///   `String` fields named like `email`, `name` or `url` get realistic values
+///   by default, see `random::Faker`.
+/// - `tag = 3` - field number in `.proto`, following fields continue from it.
+///   Numbers start from 1 in declaration order by default.
+#[proc_macro_derive(Reflected, attributes(reflected))]
 pub fn reflected(stream: TokenStream) -> TokenStream {
-    let mut stream = parse_macro_input!(stream as DeriveInput);
+    let stream = parse_macro_input!(stream as DeriveInput);
+    expand(&stream).unwrap_or_else(Error::into_compile_error).into()
+}
 
-    let Data::Struct(data) = &mut stream.data else {
-        panic!("`db_entity` macro has to be used with structs")
-    };
+fn expand(stream: &DeriveInput) -> syn::Result<TokenStream2> {
+    if !stream.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &stream.generics,
+            "`Reflected` can't be derived for generic types",
+        ));
+    }
 
-    let Fields::Named(struct_fields) = &mut data.fields else {
-        panic!()
+    let attrs = TypeAttrs::parse(&stream.attrs)?;
+
+    let struct_fields = match &stream.data {
+        Data::Enum(data) => return reflected_enum(&stream.ident, &attrs, data),
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => fields,
+        Data::Struct(_) => {
+            return Err(Error::new_spanned(
+                &stream.ident,
+                "`Reflected` can only be derived for structs with named fields",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span,
+                "`Reflected` can't be derived for unions, use a struct with named fields",
+            ))
+        }
     };
 
-    let (rename, fields) = parse_fields(struct_fields);
+    expand_struct(&stream.ident, attrs, struct_fields)
+}
 
-    let name = stream.ident.clone();
+fn expand_struct(name: &Ident, attrs: TypeAttrs, struct_fields: &FieldsNamed) -> syn::Result<TokenStream2> {
+    let fields = parse_fields(struct_fields, &attrs)?;
 
-    let name_string = if let Some(rename) = rename {
-        TokenStream2::from_str(&format!("\"{rename}\""))
-    } else {
-        TokenStream2::from_str(&format!("\"{name}\""))
-    }
-    .unwrap();
+    let name_string =
+        TokenStream2::from_str(&format!("{:?}", attrs.rename.unwrap_or_else(|| name.to_string()))).unwrap();
 
     let fields_struct_name = Ident::new(&format!("{name}Fields"), Span::call_site());
 
-    let fields_struct = fields_struct(&name, &fields);
-    let fields_const_var = fields_const_var(&name, &fields);
-    let fields_reflect = fields_reflect(&name, &fields);
-    let simple_fields_reflect = simple_fields_reflect(&name, &fields);
-    let get_value = fields_get_value(&fields);
-    let set_value = fields_set_value(&fields);
+    let fields_struct = fields_struct(name, &fields);
+    let fields_const_var = fields_const_var(&name_string, &fields);
+    let fields_reflect = fields_reflect(name, &fields);
+    let simple_fields_reflect = simple_fields_reflect(name, &fields);
+    let primary_key = primary_key(&fields);
+    let value_accessors = value_accessors(&name_string, &fields);
+    let nested = fields_nested(&fields, false);
+    let nested_mut = fields_nested(&fields, true);
+    let random = fields_random(&fields);
+
+    let arbitrary = if attrs.arbitrary.is_some() {
+        quote! {
+            reflected::__proptest_arbitrary!(#name);
+            reflected::__quickcheck_arbitrary!(#name);
+        }
+    } else {
+        quote!()
+    };
 
-    quote! {
+    Ok(quote! {
         #[derive(Debug)]
         pub struct #fields_struct_name {
             #fields_struct
@@ -57,66 +129,167 @@ pub fn reflected(stream: TokenStream) -> TokenStream {
             };
         }
 
+        #arbitrary
+
         impl reflected::Reflected for #name {
-            fn type_name() -> &'static str {
-                #name_string
-            }
+            const TYPE_NAME: &'static str = #name_string;
+
+            const PRIMARY_KEY: &'static [&'static str] = &[
+                #primary_key
+            ];
 
             fn fields() -> &'static [&'static reflected::Field<'static, Self>] {
-                &[
+                const FIELDS: &[&reflected::Field<'static, #name>] = &[
                     #fields_reflect
-                ]
+                ];
+                FIELDS
             }
 
             fn simple_fields() -> &'static [&'static reflected::Field<'static, Self>] {
-                &[
+                const FIELDS: &[&reflected::Field<'static, #name>] = &[
                     #simple_fields_reflect
-                ]
+                ];
+                FIELDS
             }
 
-            fn get_value(&self, field: &'static reflected::Field<'static, Self>) -> String {
-                use std::borrow::Borrow;
-                use reflected::ToReflectedString;
-                let field = field.borrow();
-
-                if field.is_custom() {
-                    panic!("get_value method is not supported for custom types: {field:?}");
-                }
-
+            fn nested(
+                &self,
+                field: &'static reflected::Field<'static, Self>,
+            ) -> Result<Option<&dyn reflected::DynReflected>, reflected::Error> {
                 match field.name {
-                    #get_value
-                    _ => unreachable!("Invalid field name in get_value: {}", field.name),
+                    #nested
+                    _ => Err(reflected::Error::NotStruct { field: field.name }),
                 }
             }
 
-            fn set_value(&mut self, field: &'static reflected::Field<'static, Self>, value: Option<&str>) {
-                use reflected::ToReflectedVal;
-                use std::borrow::Borrow;
-                let field = field.borrow();
+            fn nested_mut(
+                &mut self,
+                field: &'static reflected::Field<'static, Self>,
+            ) -> Result<&mut dyn reflected::DynReflected, reflected::Error> {
                 match field.name {
-                    #set_value
-                    _ => unreachable!("Invalid field name in set_value"),
+                    #nested_mut
+                    _ => Err(reflected::Error::NotStruct { field: field.name }),
                 }
             }
+
+            #[allow(unused_variables)]
+            fn random_with(rng: &mut (impl reflected::rand::Rng + ?Sized)) -> Self {
+                let mut res = Self::default();
+                #random
+                res
+            }
+
+            #value_accessors
+        }
+    })
+}
+
+/// `try_get_val` and `try_set_val`
+fn value_accessors(name_string: &TokenStream2, fields: &Vec<Field>) -> TokenStream2 {
+    let get_value = fields_get_value(fields);
+    let set_value = fields_set_value(fields);
+
+    quote! {
+        fn try_get_val(
+            &self,
+            field: &'static reflected::Field<'static, Self>,
+        ) -> Result<reflected::Value, reflected::Error> {
+            use std::borrow::Borrow;
+            use reflected::ToReflectedValue;
+            let field = field.borrow();
+
+            if field.is_custom() {
+                return Err(reflected::Error::UnsupportedCustom { field: field.name });
+            }
+
+            if field.is_struct() {
+                return Err(reflected::Error::NestedStruct { field: field.name });
+            }
+
+            match field.name {
+                #get_value
+                _ => Err(reflected::Error::UnknownField {
+                    type_name: #name_string,
+                    name:      field.name.to_string(),
+                }),
+            }
+        }
+
+        fn try_set_val(
+            &mut self,
+            field: &'static reflected::Field<'static, Self>,
+            value: reflected::Value,
+        ) -> Result<(), reflected::Error> {
+            use reflected::FromReflectedValue;
+            use std::borrow::Borrow;
+            let field = field.borrow();
+
+            if field.is_custom() {
+                return Err(reflected::Error::UnsupportedCustom { field: field.name });
+            }
+
+            if field.is_struct() {
+                return Err(reflected::Error::NestedStruct { field: field.name });
+            }
+
+            match field.name {
+                #set_value
+                _ => Err(reflected::Error::UnknownField {
+                    type_name: #name_string,
+                    name:      field.name.to_string(),
+                }),
+            }
         }
     }
-    .into()
 }
 
-fn fields_const_var(type_name: &Ident, fields: &Vec<Field>) -> TokenStream2 {
+fn fields_const_var(type_name: &TokenStream2, fields: &Vec<Field>) -> TokenStream2 {
     let mut res = quote!();
 
-    let type_name = TokenStream2::from_str(&format!("\"{type_name}\"")).unwrap();
-
     for field in fields {
         let name = &field.name;
 
         let field_type = field.field_type();
 
-        let field_type_name = field.type_as_string();
+        let field_type_name = if field.is_struct() {
+            let tp = &field.base_ty;
+            quote! { <#tp as reflected::Reflected>::TYPE_NAME }
+        } else {
+            field.type_as_string()
+        };
         let name_string = field.name_as_string();
 
+        let type_override = match &field.type_override {
+            Some(tp) => {
+                let tp = tp.to_string();
+                quote! { Some(#tp) }
+            }
+            None => quote! { None },
+        };
+
         let optional = field.optional;
+        let primary_key = field.keys.primary;
+        let foreign_key = field.keys.foreign;
+        let lenient = field.lenient;
+        let tag = field.tag;
+
+        let references = if let Some(path) = &field.references {
+            quote! {
+                Some(reflected::Reference {
+                    type_name: <#path as reflected::Reflected>::TYPE_NAME,
+                    primary_key: <#path as reflected::Reflected>::PRIMARY_KEY,
+                })
+            }
+        } else {
+            quote! { None }
+        };
+
+        let variants = if field.is_enum() {
+            let tp = &field.base_ty;
+            quote! { <#tp as reflected::ReflectedEnum>::VARIANTS }
+        } else {
+            quote! { &[] }
+        };
 
         let tp = if optional {
             quote! {
@@ -130,13 +303,24 @@ fn fields_const_var(type_name: &Ident, fields: &Vec<Field>) -> TokenStream2 {
 
         res = quote! {
             #res
-            #name: &reflected::Field {
-                name: #name_string,
-                #tp,
-                type_name: #field_type_name,
-                parent_name: #type_name,
-                optional: #optional,
-                _p: std::marker::PhantomData,
+            #name: &reflected::TypedField {
+                field: reflected::Field {
+                    name: #name_string,
+                    #tp,
+                    type_name: #field_type_name,
+                    type_override: #type_override,
+                    parent_name: #type_name,
+                    optional: #optional,
+                    variants: #variants,
+                    primary_key: #primary_key,
+                    foreign_key: #foreign_key,
+                    references: #references,
+                    lenient: #lenient,
+                    tag: #tag,
+                    _p: std::marker::PhantomData,
+                },
+                getter: |a| &a.#name,
+                getter_mut: |a| &mut a.#name,
             },
         }
     }
@@ -149,9 +333,10 @@ fn fields_struct(type_name: &Ident, fields: &Vec<Field>) -> TokenStream2 {
 
     for field in fields {
         let name = &field.name;
+        let ty = &field.ty;
         res = quote! {
             #res
-            pub #name: &'static reflected::Field<'static, #type_name>,
+            pub #name: &'static reflected::TypedField<#type_name, #ty>,
         }
     }
 
@@ -167,7 +352,7 @@ fn fields_reflect(name: &Ident, fields: &Vec<Field>) -> TokenStream2 {
         let field_name = &field.name;
         res = quote! {
             #res
-            #name::FIELDS.#field_name,
+            &#name::FIELDS.#field_name.field,
         }
     }
 
@@ -184,7 +369,24 @@ fn simple_fields_reflect(name: &Ident, fields: &Vec<Field>) -> TokenStream2 {
         let field_name = &field.name;
         res = quote! {
             #res
-            #name::FIELDS.#field_name,
+            &#name::FIELDS.#field_name.field,
+        }
+    }
+
+    res
+}
+
+fn primary_key(fields: &Vec<Field>) -> TokenStream2 {
+    let mut res = quote!();
+
+    for field in fields {
+        if !field.id() {
+            continue;
+        }
+        let name_string = field.name_as_string();
+        res = quote! {
+            #res
+            #name_string,
         }
     }
 
@@ -195,35 +397,16 @@ fn fields_get_value(fields: &Vec<Field>) -> TokenStream2 {
     let mut res = quote!();
 
     for field in fields {
-        if field.custom() {
+        if field.custom() || field.is_struct() {
             continue;
         }
 
         let field_name = &field.name;
         let name_string = field.name_as_string();
 
-        if field.is_bool() {
-            if field.optional {
-                res = quote! {
-                    #res
-                    #name_string => self.#field_name.map(|a| if a { "1" } else { "0" }.to_string()).unwrap_or("NULL".to_string()),
-                }
-            } else {
-                res = quote! {
-                    #res
-                    #name_string => if self.#field_name { "1" } else { "0" }.to_string(),
-                }
-            }
-        } else if field.optional || field.is_float() {
-            res = quote! {
-                #res
-                #name_string => self.#field_name.to_reflected_string(),
-            }
-        } else {
-            res = quote! {
-                #res
-                #name_string => self.#field_name.to_string(),
-            }
+        res = quote! {
+            #res
+            #name_string => Ok(self.#field_name.to_reflected_value()),
         }
     }
 
@@ -234,120 +417,338 @@ fn fields_set_value(fields: &Vec<Field>) -> TokenStream2 {
     let mut res = quote!();
 
     for field in fields {
-        if field.custom() {
+        if field.custom() || field.is_struct() {
             continue;
         }
 
         let field_name = &field.name;
         let name_string = field.name_as_string();
 
-        if field.is_bool() {
-            if field.optional {
-                res = quote! {
-                    #res
-                    #name_string =>  {
-                        self.#field_name = value.map(|a| match a {
-                            "0" => false,
-                            "1" => true,
-                            _ => unreachable!("Invalid value in bool: {value:?}")
-                        })
-                    },
-                }
-            } else {
-                res = quote! {
-                    #res
-                    #name_string =>  {
-                        self.#field_name = match value.unwrap() {
-                            "0" => false,
-                            "1" => true,
-                            _ => unreachable!("Invalid value in bool: {value:?}")
-                        }
-                    },
+        let null_check = if field.optional {
+            quote!()
+        } else {
+            quote! {
+                if value.is_null() {
+                    return Err(reflected::Error::NullNotOptional { field: field.name });
                 }
             }
-        } else if field.is_date() {
-            res = quote! {
-                #res
-                #name_string => self.#field_name = chrono::NaiveDateTime::parse_from_str(&value.unwrap(), "%Y-%m-%d %H:%M:%S%.9f").unwrap(),
-            }
-        } else if field.optional {
+        };
+
+        res = quote! {
+            #res
+            #name_string => {
+                #null_check
+                let text = value.to_string();
+                self.#field_name = FromReflectedValue::from_reflected_value(value)
+                    .map_err(|e| reflected::Error::parse(field.name, text, e))?;
+                Ok(())
+            },
+        }
+    }
+
+    res
+}
+
+fn fields_nested(fields: &Vec<Field>, mutable: bool) -> TokenStream2 {
+    let mut res = quote!();
+
+    for field in fields {
+        if !field.is_struct() {
+            continue;
+        }
+
+        let field_name = &field.name;
+        let name_string = field.name_as_string();
+
+        let reference = match (mutable, field.optional) {
+            (false, false) => quote! { Some(&self.#field_name) },
+            (false, true) => quote! { self.#field_name.as_ref().map(|a| a as _) },
+            (true, false) => quote! { &mut self.#field_name },
+            (true, true) => quote! { self.#field_name.get_or_insert_with(Default::default) },
+        };
+
+        res = quote! {
+            #res
+            #name_string => Ok(#reference),
+        }
+    }
+
+    res
+}
+
+fn fields_random(fields: &Vec<Field>) -> TokenStream2 {
+    let mut res = quote!();
+
+    for field in fields {
+        let field_name = &field.name;
+        let random = &field.random;
+
+        if let Some(with) = &random.with {
             res = quote! {
                 #res
-                #name_string => self.#field_name = value.map(|a| a.to_reflected_val()
-                    .expect(&format!("Failed to convert to: {} from: {}", #name_string, a))),
+                res.#field_name = {
+                    let mut rng = &mut *rng;
+                    #with(&mut rng)
+                };
+            };
+            continue;
+        }
+
+        if field.custom() {
+            continue;
+        }
+
+        let tp = &field.base_ty;
+        let name_string = field.name_as_string();
+
+        let value = if field.is_struct() {
+            quote! { <#tp as reflected::Reflected>::random_with(rng) }
+        } else if let Some(range) = &random.range {
+            quote! { <#tp as reflected::RandomRange>::random_in(rng, #range) }
+        } else if let Some(len) = &random.len {
+            quote! { reflected::random::text_with_len(rng, #len) }
+        } else if let Some(ExprRange { from, limits, to, .. }) = &random.date_range {
+            quote! {
+                <#tp as reflected::RandomRange>::random_in(
+                    rng,
+                    reflected::random::date_bound(#from) #limits reflected::random::date_bound(#to),
+                )
             }
+        } else if field.value_type() == "String" {
+            quote! { reflected::random::text_for_field(rng, #name_string) }
         } else {
-            res = quote! {
-                #res
-                #name_string => self.#field_name = value.unwrap().to_reflected_val()
-                .expect(&format!("Failed to convert to: {} from: {}", #name_string, value.unwrap())),
+            quote! { <#tp as reflected::RandomValue>::random_value_with(rng) }
+        };
+
+        let value = if field.optional {
+            quote! {
+                if reflected::rand::Rng::gen::<bool>(rng) {
+                    Some(#value)
+                } else {
+                    None
+                }
             }
+        } else {
+            value
+        };
+
+        res = quote! {
+            #res
+            res.#field_name = #value;
         }
     }
 
     res
 }
 
-fn parse_fields(fields: &FieldsNamed) -> (Option<String>, Vec<Field>) {
-    let mut rename: Option<String> = None;
+fn validate_random(field: &Field) -> syn::Result<()> {
+    let RandomAttrs {
+        span: Some(span),
+        range,
+        len,
+        date_range,
+        with,
+    } = &field.random
+    else {
+        return Ok(());
+    };
 
-    let fields: Vec<Field> = fields
-        .named
-        .iter()
-        .map(|field| {
-            let name = field.ident.as_ref().unwrap().clone();
-            let mut optional = false;
+    let field_type = field.field_type().to_string();
+
+    match (range, len, date_range, with) {
+        (None, None, None, None) => Err(Error::new_spanned(
+            span,
+            "expected one of: `range`, `len`, `date_range`, `with`",
+        )),
+        (Some(range), None, None, None) => {
+            if !matches!(field_type.as_str(), "Integer" | "Float" | "Decimal") {
+                Err(Error::new_spanned(
+                    range,
+                    "`range` is only supported for numbers and decimals",
+                ))
+            } else if is_empty(range) {
+                Err(Error::new_spanned(range, "`range` is empty"))
+            } else {
+                Ok(())
+            }
+        }
+        (None, Some(len), None, None) => {
+            if field.value_type() != "String" {
+                Err(Error::new_spanned(
+                    len,
+                    "`len` is only supported for `String` fields",
+                ))
+            } else if is_empty(len) {
+                Err(Error::new_spanned(len, "`len` is empty"))
+            } else {
+                Ok(())
+            }
+        }
+        (None, None, Some(range), None) => {
+            if field_type != "Date" {
+                return Err(Error::new_spanned(
+                    range,
+                    "`date_range` is only supported for `NaiveDateTime` fields",
+                ));
+            }
 
-            let Type::Path(path) = &field.ty else {
-                unreachable!("invalid parse_fields")
+            let is_str = |expr: &Option<Box<Expr>>| {
+                matches!(expr.as_deref(), Some(Expr::Lit(ExprLit { lit: Lit::Str(_), .. })))
             };
 
-            let mut tp = path.path.segments.first().unwrap().ident.clone();
-
-            if tp == "Option" {
-                optional = true;
-                let args = &path.path.segments.first().unwrap().arguments;
-                if let PathArguments::AngleBracketed(args) = args {
-                    if let GenericArgument::Type(generic_tp) = args.args.first().unwrap() {
-                        let ident = generic_tp.to_token_stream().to_string();
-                        let ident = Ident::new(&ident, Span::call_site());
-                        tp = ident;
-                    } else {
-                        unreachable!()
-                    }
-                } else {
-                    unreachable!()
-                }
+            if is_str(&range.from) && is_str(&range.to) {
+                Ok(())
+            } else {
+                Err(Error::new_spanned(
+                    range,
+                    "expected date strings like `\"2020-01-01\"..\"2025-01-01\"`",
+                ))
             }
+        }
+        (None, None, None, Some(_)) => Ok(()),
+        _ => Err(Error::new_spanned(
+            span,
+            "`random` takes only one of: `range`, `len`, `date_range`, `with`",
+        )),
+    }
+}
 
-            let _attrs: Vec<String> = field
-                .attrs
-                .iter()
-                .map(|a| {
-                    let name = get_attribute_name(a);
-                    if name == "name" {
-                        rename = get_attribute_value(a).expect("name attribute should have value").into();
-                    }
-                    name
-                })
-                .collect();
-
-            Field { name, tp, optional }
-        })
-        .collect();
+/// Only ranges with both bounds written as number literals are checked
+fn is_empty(range: &ExprRange) -> bool {
+    let (Some(from), Some(to)) = (
+        range.from.as_deref().and_then(number_literal),
+        range.to.as_deref().and_then(number_literal),
+    ) else {
+        return false;
+    };
 
-    (rename, fields)
+    match range.limits {
+        RangeLimits::HalfOpen(_) => from >= to,
+        RangeLimits::Closed(_) => from > to,
+    }
 }
 
-fn get_attribute_name(attribute: &Attribute) -> String {
-    attribute.path.segments.first().unwrap().ident.to_string()
+fn number_literal(expr: &Expr) -> Option<f64> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse().ok(),
+        Expr::Lit(ExprLit {
+            lit: Lit::Float(lit), ..
+        }) => lit.base10_parse().ok(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => number_literal(expr).map(|value| -value),
+        Expr::Paren(expr) => number_literal(&expr.expr),
+        _ => None,
+    }
 }
 
-fn get_attribute_value(attribute: &Attribute) -> Option<String> {
-    if let Ok(Meta::List(meta_list)) = attribute.parse_meta() {
-        if let NestedMeta::Meta(Meta::Path(path)) = &meta_list.nested[0] {
-            return Some(path.segments.last()?.ident.to_string());
+fn parse_fields(fields: &FieldsNamed, type_attrs: &TypeAttrs) -> syn::Result<Vec<Field>> {
+    let mut res = vec![];
+    let mut next_tag = 1;
+
+    for field in &fields.named {
+        let attrs = FieldAttrs::parse(field)?;
+
+        if attrs.skip {
+            continue;
         }
+
+        let name = field.ident.as_ref().unwrap().clone();
+        let ty = field.ty.clone();
+        let mut optional = false;
+
+        let Type::Path(path) = &field.ty else {
+            return Err(unsupported_type(&field.ty));
+        };
+
+        let mut base_ty = &field.ty;
+        let mut segment = path.path.segments.last().unwrap();
+
+        if segment.ident == "Option" {
+            optional = true;
+            let PathArguments::AngleBracketed(args) = &segment.arguments else {
+                return Err(Error::new_spanned(path, "expected `Option<T>`"));
+            };
+            let Some(GenericArgument::Type(generic_tp @ Type::Path(generic_path))) = args.args.first() else {
+                return Err(unsupported_type(&args.args));
+            };
+            base_ty = generic_tp;
+            segment = generic_path.path.segments.last().unwrap();
+        }
+
+        let tp = segment.ident.clone();
+        let base_ty = base_ty.clone();
+
+        let reflected_name = if let Some(rename) = attrs.rename {
+            rename
+        } else if let Some(rule) = type_attrs.rename_all {
+            rule.apply_to_field(&name.to_string())
+        } else {
+            name.to_string()
+        };
+
+        let keys = Keys {
+            primary: attrs.keys.primary || type_attrs.infer_keys && name == "id",
+            foreign: attrs.keys.foreign || type_attrs.infer_keys && name.to_string().ends_with("_id"),
+        };
+
+        let field = Field {
+            name,
+            reflected_name,
+            tp,
+            type_override: attrs.type_override.clone(),
+            ty,
+            base_ty,
+            optional,
+            kind: attrs.kind,
+            keys,
+            references: attrs.references,
+            lenient: attrs.lenient || type_attrs.lenient,
+            tag: attrs.tag.unwrap_or(next_tag),
+            random: attrs.random.unwrap_or_default(),
+        };
+
+        validate_random(&field)?;
+
+        if let Some(previous) = res.iter().find(|a: &&Field| a.tag == field.tag) {
+            return Err(Error::new_spanned(
+                &field.name,
+                format!(
+                    "field number {} is already used by `{}`",
+                    field.tag, previous.name
+                ),
+            ));
+        }
+
+        next_tag = field.tag + 1;
+
+        if let (Some(type_override), true) = (&attrs.type_override, field.custom()) {
+            return Err(Error::new_spanned(
+                type_override,
+                "unsupported type, expected a primitive, `String`, `NaiveDateTime` or `Decimal`",
+            ));
+        }
+
+        if field.optional && field.custom() {
+            return Err(Error::new_spanned(
+                &field.ty,
+                "optional custom fields are not supported, use `#[reflected(skip)]` to exclude this field",
+            ));
+        }
+
+        res.push(field);
     }
-    None
+
+    Ok(res)
+}
+
+fn unsupported_type(tokens: impl ToTokens) -> Error {
+    Error::new_spanned(
+        tokens,
+        "unsupported field type, use `#[reflected(skip)]` to exclude this field",
+    )
 }
@@ -0,0 +1,107 @@
+use std::str::FromStr;
+
+use quote::quote;
+use syn::{DataEnum, Error, Fields, Ident, __private::TokenStream2};
+
+use crate::attrs::{TypeAttrs, VariantAttrs};
+
+pub(crate) fn reflected_enum(name: &Ident, attrs: &TypeAttrs, data: &DataEnum) -> syn::Result<TokenStream2> {
+    if data.variants.is_empty() {
+        return Err(Error::new_spanned(
+            name,
+            "`Reflected` enum must have at least one variant",
+        ));
+    }
+
+    if let Some(arbitrary) = &attrs.arbitrary {
+        return Err(Error::new_spanned(
+            arbitrary,
+            "`arbitrary` is only supported on structs",
+        ));
+    }
+
+    let name_string = TokenStream2::from_str(&format!(
+        "{:?}",
+        attrs.rename.clone().unwrap_or_else(|| name.to_string())
+    ))
+    .unwrap();
+
+    let mut variant_names = quote!();
+    let mut variants = quote!();
+    let mut to_name = quote!();
+    let mut from_name = quote!();
+
+    for variant in &data.variants {
+        let Fields::Unit = variant.fields else {
+            return Err(Error::new_spanned(
+                &variant.fields,
+                "`Reflected` can only be derived for enums without fields",
+            ));
+        };
+
+        let variant_attrs = VariantAttrs::parse(&variant.attrs)?;
+
+        let variant = &variant.ident;
+
+        let variant_name = if let Some(rename) = variant_attrs.rename {
+            rename
+        } else if let Some(rule) = attrs.rename_all {
+            rule.apply_to_variant(&variant.to_string())
+        } else {
+            variant.to_string()
+        };
+
+        let variant_string = TokenStream2::from_str(&format!("{variant_name:?}")).unwrap();
+
+        variant_names = quote! {
+            #variant_names
+            #variant_string,
+        };
+
+        variants = quote! {
+            #variants
+            Self::#variant,
+        };
+
+        to_name = quote! {
+            #to_name
+            Self::#variant => #variant_string,
+        };
+
+        from_name = quote! {
+            #from_name
+            #variant_string => Some(Self::#variant),
+        };
+    }
+
+    Ok(quote! {
+        impl reflected::ReflectedEnum for #name {
+            const VARIANTS: &'static [&'static str] = &[
+                #variant_names
+            ];
+
+            fn type_name() -> &'static str {
+                #name_string
+            }
+
+            fn variants() -> &'static [Self] {
+                &[
+                    #variants
+                ]
+            }
+
+            fn variant_name(&self) -> &'static str {
+                match *self {
+                    #to_name
+                }
+            }
+
+            fn from_variant_name(name: &str) -> Option<Self> {
+                match name {
+                    #from_name
+                    _ => None,
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,182 @@
+use chrono::NaiveDateTime;
+use reflected::Reflected;
+use rust_decimal::Decimal;
+
+use crate::{
+    enums::OrderStatus,
+    nested::{Address, Coordinates},
+    CustomField,
+};
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+#[reflected(arbitrary)]
+pub struct Parcel {
+    #[reflected(id)]
+    id:       u64,
+    weight:   f32,
+    delta:    i8,
+    initial:  char,
+    price:    Decimal,
+    sent:     NaiveDateTime,
+    fragile:  bool,
+    note:     Option<String>,
+    count:    Option<u16>,
+    #[reflected(enum)]
+    status:   OrderStatus,
+    #[reflected(enum)]
+    previous: Option<OrderStatus>,
+    #[reflected(struct)]
+    address:  Address,
+    #[reflected(struct)]
+    tracking: Option<Coordinates>,
+    custom:   CustomField,
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::{
+        prelude::*,
+        strategy::ValueTree,
+        test_runner::{TestCaseError, TestError, TestRunner},
+    };
+    use quickcheck::{quickcheck, Arbitrary, Gen};
+    use reflected::{serde::AsSerde, Reflected, ReflectedEq};
+
+    use crate::{arbitrary::Parcel, nested::Customer};
+
+    fn sample<T: Reflected + std::fmt::Debug>(count: usize) -> Vec<T> {
+        let mut runner = TestRunner::deterministic();
+        let strategy = reflected::strategy::<T>();
+        (0..count).map(|_| strategy.new_tree(&mut runner).unwrap().current()).collect()
+    }
+
+    fn json_round_trip(parcel: &Parcel) -> Parcel {
+        let json = serde_json::to_string(&AsSerde(parcel.clone())).unwrap();
+        serde_json::from_str::<AsSerde<Parcel>>(&json).unwrap().0
+    }
+
+    #[test]
+    fn optional() {
+        let parcels: Vec<Parcel> = sample(100);
+
+        for name in ["note", "count", "previous"] {
+            assert!(parcels.iter().any(|a| a.value_by_name(name) == "NULL"), "{name}");
+            assert!(parcels.iter().any(|a| a.value_by_name(name) != "NULL"), "{name}");
+        }
+
+        assert!(parcels.iter().any(|a| a.tracking.is_none()));
+        assert!(parcels.iter().any(|a| a.tracking.is_some()));
+    }
+
+    #[test]
+    fn nested() {
+        let customers: Vec<Customer> = sample(20);
+
+        assert!(customers.iter().any(|a| a.value_by_path("address.city") != ""));
+        assert!(customers.iter().any(|a| a.value_by_path("address.coords.lat") != "0"));
+    }
+
+    #[test]
+    fn enums() {
+        let parcels: Vec<Parcel> = sample(50);
+
+        for status in ["Pending", "Paid", "Shipped"] {
+            assert!(parcels.iter().any(|a| a.value_by_name("status") == status));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn proptest_round_trip(parcel in any::<Parcel>()) {
+            json_round_trip(&parcel).assert_eq(&parcel);
+        }
+    }
+
+    #[test]
+    fn proptest_shrink() {
+        let mut runner = TestRunner::deterministic();
+
+        let result = runner.run(&any::<Parcel>(), |parcel| {
+            if parcel.note.is_some() {
+                return Err(TestCaseError::fail("note"));
+            }
+            Ok(())
+        });
+
+        let Err(TestError::Fail(_, parcel)) = result else {
+            panic!("Expected failure");
+        };
+
+        assert_eq!(parcel.note, Some(String::new()));
+        assert_eq!(parcel.id, 0);
+        assert!(parcel.tracking.is_none());
+    }
+
+    quickcheck! {
+        fn quickcheck_round_trip(parcel: Parcel) -> bool {
+            json_round_trip(&parcel) == parcel
+        }
+    }
+
+    #[test]
+    fn quickcheck_generate() {
+        let mut g = Gen::new(100);
+        let parcels: Vec<_> = (0..100).map(|_| Parcel::arbitrary(&mut g)).collect();
+
+        assert!(parcels.iter().any(|a| a.note.is_none()));
+        assert!(parcels.iter().any(|a| a.note.is_some()));
+        assert!(parcels.iter().any(|a| a.tracking.is_some()));
+        assert!(parcels.iter().all(|a| a.weight.is_finite()));
+
+        let customers: Vec<Customer> = (0..20).map(|_| reflected::quickcheck::arbitrary(&mut g)).collect();
+        assert!(customers.iter().any(|a| a.value_by_path("address.coords.lon") != "0"));
+    }
+
+    #[test]
+    fn quickcheck_shrink_min() {
+        let parcel = Parcel {
+            delta: i8::MIN,
+            ..Default::default()
+        };
+
+        let deltas: Vec<_> = parcel.shrink().map(|a| a.delta).filter(|a| *a != i8::MIN).collect();
+        assert!(deltas.contains(&0));
+        assert!(deltas.iter().all(|a| *a <= 0));
+    }
+
+    #[test]
+    fn quickcheck_shrink() {
+        let mut g = Gen::new(100);
+        let parcel = loop {
+            let parcel = Parcel::arbitrary(&mut g);
+            if parcel.note.is_some()
+                && parcel
+                    .tracking
+                    .as_ref()
+                    .is_some_and(|a| a.value_by_name("lat").parse::<f64>().unwrap() != 0.0)
+            {
+                break parcel;
+            }
+        };
+
+        let shrunk: Vec<_> = parcel.shrink().collect();
+
+        assert!(shrunk.iter().any(|a| a.note.is_none()));
+        assert!(shrunk
+            .iter()
+            .any(|a| a.value_by_path("tracking.lat") != parcel.value_by_path("tracking.lat")));
+        assert!(shrunk.iter().all(|a| a != &parcel));
+
+        let mut minimal = parcel;
+        while let Some(smaller) = minimal.shrink().next() {
+            minimal = smaller;
+        }
+        assert_eq!(
+            minimal,
+            Parcel {
+                tracking: minimal.tracking.clone(),
+                ..Default::default()
+            }
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use reflected::Reflected;
+
+#[derive(Reflected, Clone, Copy, Default, PartialEq, Debug)]
+#[reflected(rename = "status", rename_all = "snake_case")]
+pub enum PaymentStatus {
+    #[default]
+    NotPaid,
+    PartiallyPaid,
+    #[reflected(rename = "done")]
+    Paid,
+}
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+#[reflected(rename = "payments", rename_all = "camelCase")]
+pub struct Payment {
+    #[reflected(rename = "payment_id", id)]
+    uid:            usize,
+    #[reflected(foreign_key)]
+    payer:          usize,
+    paid_amount:    f64,
+    #[reflected(enum)]
+    payment_status: PaymentStatus,
+    #[reflected(skip)]
+    cache:          Vec<u8>,
+}
+
+#[derive(Reflected, Clone, Copy, Default, PartialEq, Debug)]
+#[reflected(rename_all = "camelCase")]
+pub enum Altitude {
+    #[default]
+    Élevé,
+    Low,
+}
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+#[reflected(rename_all = "camelCase")]
+pub struct Summit {
+    été_visits: u32,
+    #[reflected(enum)]
+    altitude:   Altitude,
+}
+
+#[cfg(test)]
+mod test {
+    use reflected::{Reflected, ReflectedEnum};
+
+    use crate::attributes::{Altitude, Payment, PaymentStatus, Summit};
+
+    #[test]
+    fn rename_type() {
+        assert_eq!(Payment::type_name(), "payments");
+        assert_eq!(Payment::FIELDS.paid_amount.parent_name, "payments");
+        assert_eq!(PaymentStatus::type_name(), "status");
+    }
+
+    #[test]
+    fn rename_fields() {
+        assert_eq!(Payment::FIELDS.uid.name, "payment_id");
+        assert_eq!(Payment::FIELDS.payer.name, "payer");
+        assert_eq!(Payment::FIELDS.paid_amount.name, "paidAmount");
+        assert_eq!(Payment::FIELDS.payment_status.name, "paymentStatus");
+
+        let mut payment = Payment::default();
+        payment.set_value(Payment::field_by_name("paidAmount"), "10.5".into());
+        assert_eq!(payment.paid_amount, 10.5);
+        assert_eq!(payment.value_by_name("paidAmount"), "10.5");
+    }
+
+    #[test]
+    fn rename_variants() {
+        assert_eq!(PaymentStatus::VARIANTS, &["not_paid", "partially_paid", "done"]);
+        assert_eq!(PaymentStatus::PartiallyPaid.variant_name(), "partially_paid");
+        assert_eq!(
+            PaymentStatus::from_variant_name("done"),
+            Some(PaymentStatus::Paid)
+        );
+
+        let mut payment = Payment::default();
+        payment.set_value(Payment::FIELDS.payment_status, "partially_paid".into());
+        assert_eq!(payment.payment_status, PaymentStatus::PartiallyPaid);
+    }
+
+    #[test]
+    fn rename_non_ascii() {
+        assert_eq!(Summit::FIELDS.été_visits.name, "étéVisits");
+        assert_eq!(Altitude::VARIANTS, &["élevé", "low"]);
+    }
+
+    #[test]
+    fn skip() {
+        assert_eq!(Payment::fields().len(), 4);
+        assert!(Payment::try_field_by_name("cache").is_err());
+
+        let payment = Payment::random();
+        assert!(payment.cache.is_empty());
+    }
+
+    #[test]
+    fn keys() {
+        assert!(Payment::FIELDS.uid.is_id());
+        assert!(!Payment::FIELDS.uid.is_foreign_id());
+        assert!(Payment::FIELDS.payer.is_foreign_id());
+        assert!(!Payment::FIELDS.paid_amount.is_id());
+
+        assert_eq!(
+            Payment::simple_fields(),
+            &[
+                &Payment::FIELDS.paid_amount.field,
+                &Payment::FIELDS.payment_status.field
+            ]
+        );
+    }
+}
@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use chrono::NaiveDateTime;
+    use reflected::{Error, HumanCodec, JsonCodec, Reflected, SqlCodec};
+    use rust_decimal::Decimal;
+
+    use crate::{enums::Order, User};
+
+    fn user() -> User {
+        User {
+            name: "O'Brien \"Bob\"".into(),
+            birthday: NaiveDateTime::from_str("2024-05-01T10:20:30.5").unwrap(),
+            age: 30,
+            cash: Decimal::new(10025, 2),
+            is_poros: true,
+            height: 2.0,
+            str_opt: Some("NULL".into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn sql() {
+        let user = user();
+
+        assert_eq!(
+            user.get_value_with(&SqlCodec, User::FIELDS.name),
+            "'O''Brien \"Bob\"'"
+        );
+        assert_eq!(
+            user.get_value_with(&SqlCodec, User::FIELDS.birthday),
+            "'2024-05-01 10:20:30.500'"
+        );
+        assert_eq!(user.get_value_with(&SqlCodec, User::FIELDS.age), "30");
+        assert_eq!(user.get_value_with(&SqlCodec, User::FIELDS.cash), "100.25");
+        assert_eq!(user.get_value_with(&SqlCodec, User::FIELDS.is_poros), "1");
+        assert_eq!(user.get_value_with(&SqlCodec, User::FIELDS.height), "2");
+        assert_eq!(user.get_value_with(&SqlCodec, User::FIELDS.str_opt), "'NULL'");
+        assert_eq!(user.get_value_with(&SqlCodec, User::FIELDS.usize_opt), "NULL");
+
+        round_trip(&SqlCodec, &user);
+    }
+
+    #[test]
+    fn json() {
+        let user = user();
+
+        assert_eq!(
+            user.get_value_with(&JsonCodec, User::FIELDS.name),
+            r#""O'Brien \"Bob\"""#
+        );
+        assert_eq!(
+            user.get_value_with(&JsonCodec, User::FIELDS.birthday),
+            r#""2024-05-01T10:20:30.500""#
+        );
+        assert_eq!(user.get_value_with(&JsonCodec, User::FIELDS.cash), r#""100.25""#);
+        assert_eq!(user.get_value_with(&JsonCodec, User::FIELDS.is_poros), "true");
+        assert_eq!(user.get_value_with(&JsonCodec, User::FIELDS.str_opt), r#""NULL""#);
+        assert_eq!(user.get_value_with(&JsonCodec, User::FIELDS.usize_opt), "null");
+
+        round_trip(&JsonCodec, &user);
+
+        let mut user = User::default();
+        user.set_value_with(&JsonCodec, User::FIELDS.name, r#""tab\tsmile 😀""#);
+        user.set_value_with(&JsonCodec, User::FIELDS.cash, "5.5");
+        user.set_value_with(&JsonCodec, User::FIELDS.birthday, r#""2024-05-01T10:20:30Z""#);
+        assert_eq!(user.get_value(User::FIELDS.name), "tab\tsmile 😀");
+        assert_eq!(user.get_value(User::FIELDS.cash), "5.5");
+        assert_eq!(user.get_value(User::FIELDS.birthday), "2024-05-01 10:20:30");
+
+        assert!(user.try_set_value_with(&JsonCodec, User::FIELDS.name, "bob").is_err());
+        assert!(user.try_set_value_with(&JsonCodec, User::FIELDS.is_poros, "1").is_err());
+    }
+
+    #[test]
+    fn human() {
+        let mut user = user();
+
+        assert_eq!(
+            user.get_value_with(&HumanCodec, User::FIELDS.name),
+            "O'Brien \"Bob\""
+        );
+        assert_eq!(
+            user.get_value_with(&HumanCodec, User::FIELDS.birthday),
+            "2024-05-01 10:20:30"
+        );
+        assert_eq!(user.get_value_with(&HumanCodec, User::FIELDS.is_poros), "yes");
+        assert_eq!(user.get_value_with(&HumanCodec, User::FIELDS.height), "2");
+        assert_eq!(user.get_value_with(&HumanCodec, User::FIELDS.usize_opt), "");
+
+        user.set_value_with(&HumanCodec, User::FIELDS.is_poros, "No");
+        user.set_value_with(&HumanCodec, User::FIELDS.birthday, "2023-01-02");
+        user.set_value_with(&HumanCodec, User::FIELDS.str_opt, " ");
+        user.set_value_with(&HumanCodec, User::FIELDS.name, "");
+        user.set_value_with(&HumanCodec, User::FIELDS.age, " 7 ");
+
+        assert!(!user.is_poros);
+        assert_eq!(user.get_value(User::FIELDS.birthday), "2023-01-02 00:00:00");
+        assert_eq!(user.str_opt, None);
+        assert_eq!(user.name, "");
+        assert_eq!(user.age, 7);
+
+        assert_eq!(
+            user.try_set_value_with(&HumanCodec, User::FIELDS.age, ""),
+            Err(Error::NullNotOptional { field: "age" })
+        );
+        assert_eq!(
+            user.try_set_value_with(&HumanCodec, User::FIELDS.is_poros, "maybe"),
+            Err(Error::parse("is_poros", "maybe", "expected `yes` or `no`"))
+        );
+    }
+
+    #[test]
+    fn enums() {
+        let mut order = Order::default();
+
+        order.set_value_with(&SqlCodec, Order::FIELDS.status, "'Paid'");
+        order.set_value_with(&JsonCodec, Order::FIELDS.previous_status, r#""Shipped""#);
+
+        assert_eq!(order.get_value_with(&SqlCodec, Order::FIELDS.status), "'Paid'");
+        assert_eq!(
+            order.get_value_with(&HumanCodec, Order::FIELDS.previous_status),
+            "Shipped"
+        );
+
+        assert!(order.try_set_value_with(&SqlCodec, Order::FIELDS.status, "'Lost'").is_err());
+    }
+
+    fn round_trip(codec: &impl reflected::ReflectedCodec, user: &User) {
+        let mut copy = User::default();
+
+        for field in User::fields() {
+            if field.is_custom() {
+                continue;
+            }
+            copy.set_value_with(codec, field, &user.get_value_with(codec, field));
+        }
+
+        assert_eq!(&copy, user);
+    }
+}
@@ -0,0 +1,7 @@
+#[cfg(test)]
+mod test {
+    #[test]
+    fn compile_fail() {
+        trybuild::TestCases::new().compile_fail("ui/*.rs");
+    }
+}
@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod test {
+    use reflected::{
+        csv::{self, Error, Format, Reader, Writer},
+        Reflected, ReflectedEq,
+    };
+
+    use crate::{nested::Customer, User};
+
+    const HEADER: &str =
+        "id,name,birthday,age,custom_id,cash,is_poros,height,str_opt,usize_opt,bool_opt,decimal_opt\n";
+
+    fn users() -> Vec<User> {
+        let mut users: Vec<User> = (0..5).map(|_| User::random()).collect();
+
+        users[0].set_value(User::FIELDS.name, Some("comma, \"quote\"\nnew line"));
+        users[1].set_value(User::FIELDS.name, Some(""));
+        users[2].set_value(User::FIELDS.str_opt, Some(""));
+        users[3].set_value(User::FIELDS.str_opt, None);
+        users[4].set_value(User::FIELDS.usize_opt, None);
+
+        users
+    }
+
+    #[test]
+    fn write() {
+        let mut user = User::default();
+        user.set_value(User::FIELDS.name, Some("Bob, \"Jr\""));
+        user.set_value(User::FIELDS.height, Some("1.5"));
+
+        let csv = csv::to_string(&[user]);
+
+        assert_eq!(
+            csv,
+            format!("{HEADER}0,\"Bob, \"\"Jr\"\"\",1970-01-01 00:00:00,0,0,0,0,1.5,,,,\n")
+        );
+
+        assert_eq!(csv::to_string::<User>(&[]), HEADER);
+    }
+
+    #[test]
+    fn round_trip() {
+        let users = users();
+
+        let parsed: Vec<User> = csv::from_str(&csv::to_string(&users)).unwrap();
+
+        assert_eq!(parsed.len(), users.len());
+        for (user, parsed) in users.iter().zip(&parsed) {
+            user.assert_eq(parsed);
+        }
+
+        assert_eq!(parsed[2].get_value(User::FIELDS.str_opt), "");
+        assert_eq!(parsed[3].get_value(User::FIELDS.str_opt), "NULL");
+    }
+
+    #[test]
+    fn format() {
+        let users = users();
+        let format = Format {
+            delimiter: ';',
+            null:      "NULL".into(),
+        };
+
+        let mut writer = Writer::with_format(vec![], format.clone());
+        writer.write_all(&users).unwrap();
+        let csv = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+
+        assert!(csv.lines().next().unwrap().starts_with("id;name;birthday"));
+
+        let parsed = Reader::<_, User>::with_format(csv.as_bytes(), format)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        for (user, parsed) in users.iter().zip(&parsed) {
+            user.assert_eq(parsed);
+        }
+    }
+
+    #[test]
+    fn read() {
+        let csv = "\u{feff}age,name,str_opt\r\n\r\n5,\"multi\r\nline\",\n7,plain,\"\"\n";
+
+        let users: Vec<User> = csv::from_str(csv).unwrap();
+
+        assert_eq!(users.len(), 2);
+        assert_eq!(users[0].get_value(User::FIELDS.age), "5");
+        assert_eq!(users[0].get_value(User::FIELDS.name), "multi\r\nline");
+        assert_eq!(users[0].get_value(User::FIELDS.str_opt), "NULL");
+        assert_eq!(users[1].get_value(User::FIELDS.name), "plain");
+        assert_eq!(users[1].get_value(User::FIELDS.str_opt), "");
+        assert_eq!(users[1].get_value(User::FIELDS.id), "0");
+
+        assert!(csv::from_str::<User>("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn errors() {
+        let error = |csv: &str| csv::from_str::<User>(csv).unwrap_err().to_string();
+
+        assert_eq!(
+            error("name,age\nBob,5\nAlice,old\n"),
+            "CSV error at row 3, column 2: Failed to parse `age` from \"old\": invalid digit found in string"
+        );
+        assert_eq!(
+            error("name,age\nBob\n"),
+            "CSV error at row 2, column 2: expected 2 columns, got 1"
+        );
+        assert_eq!(
+            error("name,age\n\"Bob,5\n"),
+            "CSV error at row 2, column 1: unterminated quoted value"
+        );
+        assert_eq!(
+            error("name,age\n\"Bob\"x,5\n"),
+            "CSV error at row 2, column 1: expected delimiter after closing quote"
+        );
+        assert_eq!(
+            error("name,age\nB\"ob,5\n"),
+            "CSV error at row 2, column 1: unexpected quote in unquoted value"
+        );
+        assert_eq!(
+            error("name,nope\n"),
+            "CSV error at row 1, column 2: Unknown field `nope` in `User`"
+        );
+        assert_eq!(
+            error("name,name\n"),
+            "CSV error at row 1, column 2: duplicate column `name`"
+        );
+        assert!(matches!(
+            csv::from_str::<Customer>("address\n"),
+            Err(Error::Reflected {
+                row: 1,
+                column: 1,
+                ..
+            })
+        ));
+    }
+}
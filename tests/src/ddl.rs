@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod test {
+    use reflected::sql::{create_table, create_table_if_not_exists, Dialect};
+
+    use crate::{enums::Order, keys::Shift, optional::Optionals, User};
+
+    #[test]
+    fn sqlite() {
+        assert_eq!(
+            create_table::<User>(Dialect::Sqlite),
+            r#"CREATE TABLE "User" (
+    "id" INTEGER NOT NULL,
+    "name" TEXT NOT NULL,
+    "birthday" TEXT NOT NULL,
+    "age" INTEGER NOT NULL,
+    "custom_id" INTEGER NOT NULL,
+    "cash" TEXT NOT NULL,
+    "is_poros" INTEGER NOT NULL,
+    "height" REAL NOT NULL,
+    "str_opt" TEXT,
+    "usize_opt" INTEGER,
+    "bool_opt" INTEGER,
+    "decimal_opt" TEXT,
+    PRIMARY KEY ("id"),
+    FOREIGN KEY ("custom_id") REFERENCES "Custom" ("id")
+);"#
+        );
+    }
+
+    #[test]
+    fn postgres() {
+        assert_eq!(
+            create_table_if_not_exists::<Shift>(Dialect::Postgres),
+            r#"CREATE TABLE IF NOT EXISTS "Shift" (
+    "worker_id" BIGINT NOT NULL,
+    "day" BIGINT NOT NULL,
+    "manager" BIGINT NOT NULL,
+    "site" BIGINT NOT NULL,
+    "hours" DOUBLE PRECISION NOT NULL,
+    PRIMARY KEY ("worker_id", "day"),
+    FOREIGN KEY ("manager") REFERENCES "workers" ("id"),
+    FOREIGN KEY ("site") REFERENCES "Site" ("id")
+);"#
+        );
+
+        assert_eq!(
+            create_table::<Order>(Dialect::Postgres),
+            r#"CREATE TABLE "Order" (
+    "id" BIGINT NOT NULL,
+    "status" TEXT NOT NULL CONSTRAINT "Order_status_check" CHECK ("status" IN ('Pending', 'Paid', 'Shipped')),
+    "previous_status" TEXT CONSTRAINT "Order_previous_status_check" CHECK ("previous_status" IN ('Pending', 'Paid', 'Shipped')),
+    PRIMARY KEY ("id")
+);"#
+        );
+    }
+
+    #[test]
+    fn mysql() {
+        assert_eq!(
+            create_table::<Order>(Dialect::MySql),
+            r#"CREATE TABLE `Order` (
+    `id` BIGINT UNSIGNED NOT NULL,
+    `status` ENUM('Pending', 'Paid', 'Shipped') NOT NULL,
+    `previous_status` ENUM('Pending', 'Paid', 'Shipped'),
+    PRIMARY KEY (`id`)
+);"#
+        );
+    }
+
+    #[test]
+    fn column_types() {
+        let types = |dialect: Dialect| {
+            [
+                &Optionals::FIELDS.int8.field,
+                &Optionals::FIELDS.uint32.field,
+                &Optionals::FIELDS.int128.field,
+                &Optionals::FIELDS.float32.field,
+                &Optionals::FIELDS.letter.field,
+                &Optionals::FIELDS.date.field,
+                &Optionals::FIELDS.decimal.field,
+                &Optionals::FIELDS.boolean.field,
+                &Optionals::FIELDS.location.field,
+            ]
+            .map(|field| dialect.column_type(field))
+        };
+
+        assert_eq!(
+            types(Dialect::Postgres),
+            [
+                Some("SMALLINT".into()),
+                Some("BIGINT".into()),
+                Some("NUMERIC(39, 0)".into()),
+                Some("REAL".into()),
+                Some("CHAR(1)".into()),
+                Some("TIMESTAMP".into()),
+                Some("NUMERIC".into()),
+                Some("BOOLEAN".into()),
+                None,
+            ]
+        );
+        assert_eq!(
+            types(Dialect::MySql),
+            [
+                Some("TINYINT".into()),
+                Some("INT UNSIGNED".into()),
+                Some("DECIMAL(39, 0)".into()),
+                Some("FLOAT".into()),
+                Some("CHAR(1)".into()),
+                Some("DATETIME(6)".into()),
+                Some("DECIMAL(38, 10)".into()),
+                Some("BOOLEAN".into()),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn quote() {
+        assert_eq!(Dialect::Sqlite.quote("a\"b"), "\"a\"\"b\"");
+        assert_eq!(Dialect::MySql.quote("a`b"), "`a``b`");
+    }
+}
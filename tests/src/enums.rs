@@ -0,0 +1,92 @@
+use reflected::Reflected;
+
+#[derive(Reflected, Clone, Copy, Default, PartialEq, Debug)]
+pub enum OrderStatus {
+    #[default]
+    Pending,
+    Paid,
+    Shipped,
+}
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+pub struct Order {
+    #[reflected(id)]
+    id:              usize,
+    #[reflected(enum)]
+    status:          OrderStatus,
+    #[reflected(enum)]
+    previous_status: Option<OrderStatus>,
+}
+
+#[cfg(test)]
+mod test {
+    use reflected::{Reflected, ReflectedEnum, ReflectedEq};
+
+    use crate::enums::{Order, OrderStatus};
+
+    #[test]
+    fn variants() {
+        assert_eq!(OrderStatus::type_name(), "OrderStatus");
+        assert_eq!(OrderStatus::VARIANTS, &["Pending", "Paid", "Shipped"]);
+        assert_eq!(
+            OrderStatus::variants(),
+            &[OrderStatus::Pending, OrderStatus::Paid, OrderStatus::Shipped]
+        );
+
+        assert_eq!(OrderStatus::Paid.variant_name(), "Paid");
+        assert_eq!(
+            OrderStatus::from_variant_name("Shipped"),
+            Some(OrderStatus::Shipped)
+        );
+        assert_eq!(OrderStatus::from_variant_name("Lost"), None);
+        assert_eq!(OrderStatus::from_variant_name("pending"), None);
+    }
+
+    #[test]
+    fn fields() {
+        assert!(Order::FIELDS.status.is_enum());
+        assert!(!Order::FIELDS.status.is_optional());
+        assert!(Order::FIELDS.previous_status.is_enum());
+        assert!(Order::FIELDS.previous_status.is_optional());
+
+        assert_eq!(Order::FIELDS.status.type_name, "OrderStatus");
+        assert_eq!(Order::FIELDS.status.variants, OrderStatus::VARIANTS);
+        assert_eq!(Order::FIELDS.previous_status.variants, OrderStatus::VARIANTS);
+
+        assert_eq!(Order::simple_fields().len(), 2);
+    }
+
+    #[test]
+    fn get_set() {
+        let mut order = Order::default();
+
+        assert_eq!(order.get_value(Order::FIELDS.status), "Pending");
+        assert_eq!(order.get_value(Order::FIELDS.previous_status), "NULL");
+
+        order.set_value(Order::FIELDS.status, "Shipped".into());
+        order.set_value(Order::FIELDS.previous_status, "Paid".into());
+
+        assert_eq!(order.status, OrderStatus::Shipped);
+        assert_eq!(order.previous_status, Some(OrderStatus::Paid));
+        assert_eq!(order.get_value(Order::FIELDS.status), "Shipped");
+        assert_eq!(order.get_value(Order::FIELDS.previous_status), "Paid");
+
+        order.set_value(Order::FIELDS.previous_status, None);
+        assert_eq!(order.previous_status, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to parse `status` from \"Lost\": Invalid OrderStatus variant: Lost")]
+    fn set_invalid() {
+        Order::default().set_value(Order::FIELDS.status, "Lost".into());
+    }
+
+    #[test]
+    fn random() {
+        for _ in 0..20 {
+            let order = Order::random();
+            assert!(OrderStatus::variants().contains(&order.status));
+            order.assert_eq(&order.clone());
+        }
+    }
+}
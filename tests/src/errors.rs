@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod test {
+    use reflected::{Error, Reflected};
+
+    use crate::User;
+
+    #[test]
+    fn field_by_name() {
+        assert_eq!(User::try_field_by_name("age"), Ok(User::field_by_name("age")));
+        assert_eq!(
+            User::try_field_by_name("agee"),
+            Err(Error::UnknownField {
+                type_name: "User",
+                name:      "agee".into(),
+            })
+        );
+        assert_eq!(
+            User::default().try_value_by_name("agee").unwrap_err().to_string(),
+            "Unknown field `agee` in `User`"
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let mut user = User::default();
+
+        assert_eq!(
+            user.try_set_value(User::FIELDS.age, "old".into()),
+            Err(Error::Parse {
+                field: "age",
+                value: "old".into(),
+                error: "invalid digit found in string".into(),
+            })
+        );
+
+        assert_eq!(
+            user.try_set_value(User::FIELDS.is_poros, "yes".into()),
+            Err(Error::parse("is_poros", "yes", "expected `0` or `1`"))
+        );
+        assert!(user.try_set_value(User::FIELDS.bool_opt, "2".into()).is_err());
+        assert!(user.try_set_value(User::FIELDS.usize_opt, "-5".into()).is_err());
+        assert!(user.try_set_value(User::FIELDS.birthday, "yesterday".into()).is_err());
+        assert!(user.try_set_value(User::FIELDS.cash, "1.2.3".into()).is_err());
+
+        assert_eq!(user, User::default());
+    }
+
+    #[test]
+    fn null() {
+        let mut user = User::default();
+
+        assert_eq!(
+            user.try_set_value(User::FIELDS.name, None),
+            Err(Error::NullNotOptional { field: "name" })
+        );
+        assert_eq!(
+            user.try_set_value(User::FIELDS.birthday, None),
+            Err(Error::NullNotOptional { field: "birthday" })
+        );
+        assert_eq!(user.try_set_value(User::FIELDS.str_opt, None), Ok(()));
+    }
+
+    #[test]
+    fn custom() {
+        let mut user = User::default();
+
+        assert_eq!(
+            user.try_get_value(User::FIELDS.custom),
+            Err(Error::UnsupportedCustom { field: "custom" })
+        );
+        assert_eq!(
+            user.try_set_value(User::FIELDS.custom, "".into()),
+            Err(Error::UnsupportedCustom { field: "custom" })
+        );
+    }
+
+    #[test]
+    fn ok() {
+        let mut user = User::default();
+
+        assert_eq!(user.try_set_value(User::FIELDS.age, "42".into()), Ok(()));
+        assert_eq!(user.try_get_value(User::FIELDS.age), Ok("42".to_string()));
+        assert_eq!(user.try_value_by_name("age"), Ok("42".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown field `agee` in `User`")]
+    fn panicking_wrapper() {
+        User::field_by_name("agee");
+    }
+}
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod test {
+    use reflected::schema::graphql;
+
+    use crate::{
+        json_schema::{Depot, Shipment},
+        proto::Payment,
+    };
+
+    #[test]
+    fn object_types() {
+        assert_eq!(
+            graphql::<Shipment>(),
+            r#"scalar Decimal
+scalar DateTime
+
+enum OrderStatus {
+  Pending
+  Paid
+  Shipped
+}
+
+type Shipment {
+  id: ID!
+  weight: Float!
+  delta: Int!
+  price: Decimal!
+  sent: DateTime!
+  express: Boolean!
+  note: String
+  status: OrderStatus
+  address: Address!
+  tracking: Coordinates
+}
+
+type Address {
+  city: String!
+  street: String!
+  coords: Coordinates!
+}
+
+type Coordinates {
+  lat: Float!
+  lon: Float!
+}
+"#
+        );
+    }
+
+    #[test]
+    fn scalars() {
+        assert!(graphql::<Payment>().starts_with("enum OrderStatus {"));
+        assert!(graphql::<crate::User>().starts_with("scalar DateTime\nscalar BigInt\nscalar Decimal\n"));
+    }
+
+    #[test]
+    fn renamed_nested() {
+        assert_eq!(
+            graphql::<Depot>(),
+            r#"type Depot {
+  id: ID!
+  location: locations!
+  backup: locations
+}
+
+type locations {
+  city: String!
+}
+"#
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use chrono::NaiveDateTime;
+use reflected::Reflected;
+use rust_decimal::Decimal;
+
+use crate::{
+    enums::OrderStatus,
+    nested::{Address, Coordinates},
+    CustomField,
+};
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+pub struct Shipment {
+    #[reflected(id)]
+    id:       u64,
+    weight:   f32,
+    delta:    i32,
+    price:    Decimal,
+    sent:     NaiveDateTime,
+    express:  bool,
+    note:     Option<String>,
+    #[reflected(enum)]
+    status:   Option<OrderStatus>,
+    #[reflected(struct)]
+    address:  Address,
+    #[reflected(struct)]
+    tracking: Option<Coordinates>,
+    custom:   CustomField,
+}
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+#[reflected(rename = "locations")]
+pub struct Location {
+    city: String,
+}
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+pub struct Depot {
+    #[reflected(id)]
+    id:       u32,
+    #[reflected(struct)]
+    location: Location,
+    #[reflected(struct)]
+    backup:   Option<Location>,
+}
+
+#[cfg(test)]
+mod test {
+    use reflected::schema::{json_schema, Definition};
+    use serde_json::{json, Value};
+
+    use crate::{
+        json_schema::{Depot, Shipment},
+        nested::Customer,
+    };
+
+    #[test]
+    fn definitions() {
+        let names: Vec<_> = Definition::all::<Shipment>().into_iter().map(|a| a.name).collect();
+        assert_eq!(names, ["Shipment", "Address", "Coordinates"]);
+
+        let definition = Definition::of::<Shipment>();
+        assert!(definition.properties.iter().all(|a| a.name != "custom"));
+    }
+
+    #[test]
+    fn schema() {
+        let schema: Value = serde_json::from_str(&json_schema::<Shipment>()).unwrap();
+
+        assert_eq!(
+            schema,
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "title": "Shipment",
+                "type": "object",
+                "properties": {
+                    "id": { "type": "integer", "minimum": 0 },
+                    "weight": { "type": "number" },
+                    "delta": { "type": "integer" },
+                    "price": { "type": "string", "pattern": "^-?[0-9]+(\\.[0-9]+)?$" },
+                    "sent": { "type": "string", "format": "date-time" },
+                    "express": { "type": "boolean" },
+                    "note": { "type": ["string", "null"] },
+                    "status": { "type": ["string", "null"], "enum": ["Pending", "Paid", "Shipped", null] },
+                    "address": { "$ref": "#/$defs/Address" },
+                    "tracking": { "anyOf": [{ "$ref": "#/$defs/Coordinates" }, { "type": "null" }] },
+                },
+                "required": ["id", "weight", "delta", "price", "sent", "express", "address"],
+                "$defs": {
+                    "Address": {
+                        "title": "Address",
+                        "type": "object",
+                        "properties": {
+                            "city": { "type": "string" },
+                            "street": { "type": "string" },
+                            "coords": { "$ref": "#/$defs/Coordinates" },
+                        },
+                        "required": ["city", "street", "coords"],
+                    },
+                    "Coordinates": {
+                        "title": "Coordinates",
+                        "type": "object",
+                        "properties": {
+                            "lat": { "type": "number" },
+                            "lon": { "type": "number" },
+                        },
+                        "required": ["lat", "lon"],
+                    },
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn no_defs() {
+        let schema: Value = serde_json::from_str(&json_schema::<crate::User>()).unwrap();
+
+        assert!(schema.get("$defs").is_none());
+        assert_eq!(schema["properties"]["birthday"]["format"], "date-time");
+
+        let schema: Value = serde_json::from_str(&json_schema::<Customer>()).unwrap();
+        assert_eq!(schema["$defs"].as_object().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn renamed_nested() {
+        let schema: Value = serde_json::from_str(&json_schema::<Depot>()).unwrap();
+
+        assert_eq!(
+            schema["properties"]["location"],
+            json!({ "$ref": "#/$defs/locations" })
+        );
+        assert_eq!(
+            schema["properties"]["backup"],
+            json!({ "anyOf": [{ "$ref": "#/$defs/locations" }, { "type": "null" }] })
+        );
+        assert_eq!(schema["$defs"]["locations"]["title"], "locations");
+    }
+}
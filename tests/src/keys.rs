@@ -0,0 +1,80 @@
+use reflected::Reflected;
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+#[reflected(rename = "workers")]
+pub struct Worker {
+    #[reflected(id)]
+    id:             usize,
+    paid_idle_time: usize,
+}
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+pub struct Shift {
+    #[reflected(id)]
+    worker_id: usize,
+    #[reflected(id)]
+    day:       usize,
+    #[reflected(foreign_key = "Worker")]
+    manager:   usize,
+    #[reflected(foreign_key)]
+    site:      usize,
+    hours:     f64,
+}
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+#[reflected(infer_keys)]
+pub struct Visit {
+    id:             usize,
+    shift_id:       usize,
+    paid_idle_time: usize,
+}
+
+#[cfg(test)]
+mod test {
+    use reflected::{Reference, Reflected};
+
+    use crate::keys::{Shift, Visit, Worker};
+
+    #[test]
+    fn no_heuristic_by_default() {
+        assert!(Worker::FIELDS.id.is_id());
+        assert!(!Worker::FIELDS.paid_idle_time.is_foreign_id());
+        assert_eq!(Worker::simple_fields(), &[&Worker::FIELDS.paid_idle_time.field]);
+        assert!(Worker::foreign_keys().is_empty());
+    }
+
+    #[test]
+    fn composite_primary_key() {
+        assert_eq!(Shift::PRIMARY_KEY, &["worker_id", "day"]);
+        assert_eq!(
+            Shift::primary_key_fields(),
+            vec![&Shift::FIELDS.worker_id.field, &Shift::FIELDS.day.field]
+        );
+        assert!(!Shift::FIELDS.worker_id.is_foreign_id());
+    }
+
+    #[test]
+    fn foreign_keys() {
+        assert_eq!(
+            Shift::foreign_keys(),
+            vec![&Shift::FIELDS.manager.field, &Shift::FIELDS.site.field]
+        );
+        assert_eq!(
+            Shift::FIELDS.manager.references,
+            Some(Reference {
+                type_name:   "workers",
+                primary_key: &["id"],
+            })
+        );
+        assert_eq!(Shift::FIELDS.site.references, None);
+        assert_eq!(Shift::simple_fields(), &[&Shift::FIELDS.hours.field]);
+    }
+
+    #[test]
+    fn infer_keys() {
+        assert_eq!(Visit::PRIMARY_KEY, &["id"]);
+        assert_eq!(Visit::foreign_keys(), vec![&Visit::FIELDS.shift_id.field]);
+        assert!(!Visit::FIELDS.paid_idle_time.is_foreign_id());
+        assert_eq!(Visit::simple_fields(), &[&Visit::FIELDS.paid_idle_time.field]);
+    }
+}
@@ -0,0 +1,151 @@
+use chrono::NaiveDateTime;
+use reflected::Reflected;
+use rust_decimal::Decimal;
+
+use crate::enums::OrderStatus;
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+pub struct Import {
+    #[reflected(lenient)]
+    count:  u32,
+    strict: u32,
+    #[reflected(lenient, enum)]
+    status: Option<OrderStatus>,
+}
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+#[reflected(lenient)]
+pub struct FormPost {
+    active:  bool,
+    score:   f64,
+    price:   Decimal,
+    created: Option<NaiveDateTime>,
+    comment: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use reflected::{Error, Reflected, Value};
+    use rust_decimal::Decimal;
+
+    use crate::{
+        lenient::{FormPost, Import},
+        User,
+    };
+
+    #[test]
+    fn per_call() {
+        let mut user = User::default();
+
+        assert_eq!(
+            user.set_value_lenient(User::FIELDS.is_poros, "Yes".into()),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            user.set_value_lenient(User::FIELDS.age, " 42.0 ".into()),
+            Value::Int(42)
+        );
+        assert_eq!(
+            user.set_value_lenient(User::FIELDS.height, "1,5".into()),
+            Value::Float(1.5)
+        );
+        assert_eq!(
+            user.set_value_lenient(User::FIELDS.cash, "+1_000.50".into()).to_string(),
+            "1000.50"
+        );
+        assert_eq!(
+            user.set_value_lenient(User::FIELDS.birthday, "2024-05-01".into()).to_string(),
+            "2024-05-01 00:00:00"
+        );
+        assert_eq!(
+            user.set_value_lenient(User::FIELDS.birthday, "2024-05-01T10:20:30+02:00".into())
+                .to_string(),
+            "2024-05-01 08:20:30"
+        );
+        assert_eq!(
+            user.set_value_lenient(User::FIELDS.usize_opt, "".into()),
+            Value::Null
+        );
+        assert_eq!(
+            user.set_value_lenient(User::FIELDS.bool_opt, "null".into()),
+            Value::Null
+        );
+        assert_eq!(
+            user.set_value_lenient(User::FIELDS.str_opt, "".into()),
+            Value::Text("".into())
+        );
+        assert_eq!(
+            user.set_value_lenient(User::FIELDS.str_opt, "NULL".into()),
+            Value::Null
+        );
+
+        assert!(user.is_poros);
+        assert_eq!(user.age, 42);
+        assert_eq!(user.height, 1.5);
+        assert_eq!(user.cash, Decimal::new(100050, 2));
+
+        assert_eq!(
+            user.try_set_value_lenient(User::FIELDS.age, "42.5".into()),
+            Err(Error::parse("age", "42.5", "invalid digit found in string"))
+        );
+        assert_eq!(
+            user.try_set_value_lenient(User::FIELDS.age, "".into()),
+            Err(Error::NullNotOptional { field: "age" })
+        );
+        assert!(user.try_set_value_lenient(User::FIELDS.is_poros, "maybe".into()).is_err());
+
+        assert_eq!(
+            user.try_set_value_lenient(User::FIELDS.height, "1,000".into()),
+            Err(Error::parse(
+                "height",
+                "1,000",
+                "ambiguous `,`, use `.` as decimal separator"
+            ))
+        );
+        assert!(user.try_set_value_lenient(User::FIELDS.cash, "1,000,000".into()).is_err());
+        assert_eq!(
+            user.set_value_lenient(User::FIELDS.cash, "0,25".into()).to_string(),
+            "0.25"
+        );
+
+        assert!(user.try_set_value(User::FIELDS.is_poros, "yes".into()).is_err());
+        assert!(user.try_set_value(User::FIELDS.age, "42.0".into()).is_err());
+    }
+
+    #[test]
+    fn per_field() {
+        let mut import = Import::default();
+
+        assert!(Import::FIELDS.count.lenient);
+        assert!(!Import::FIELDS.strict.lenient);
+
+        import.set_value(Import::FIELDS.count, "7.000".into());
+        assert!(import.try_set_value(Import::FIELDS.strict, "7.000".into()).is_err());
+        import.set_value(Import::FIELDS.status, " shipped ".into());
+
+        assert_eq!(import.get_value(Import::FIELDS.count), "7");
+        assert_eq!(import.get_value(Import::FIELDS.status), "Shipped");
+
+        import.set_value_by_path("status", "".into());
+        assert_eq!(import.get_value(Import::FIELDS.status), "NULL");
+    }
+
+    #[test]
+    fn per_type() {
+        let mut post = FormPost::default();
+
+        assert!(FormPost::fields().iter().all(|a| a.lenient));
+
+        post.set_value(FormPost::FIELDS.active, "on".into());
+        post.set_value(FormPost::FIELDS.score, "3".into());
+        post.set_value(FormPost::FIELDS.price, "9,99".into());
+        post.set_value(FormPost::FIELDS.created, "2024-05-01 10:20".into());
+        post.set_value(FormPost::FIELDS.comment, "  ".into());
+
+        assert_eq!(post.get_value(FormPost::FIELDS.active), "1");
+        assert_eq!(post.get_value(FormPost::FIELDS.score), "3.0");
+        assert_eq!(post.get_value(FormPost::FIELDS.price), "9.99");
+        assert_eq!(post.get_value(FormPost::FIELDS.created), "2024-05-01 10:20:00");
+        assert_eq!(post.get_value(FormPost::FIELDS.comment), "  ");
+    }
+}
@@ -1,3 +1,29 @@
+mod arbitrary;
+mod attributes;
+mod codecs;
+mod compile_fail;
+mod csv;
+mod ddl;
+mod enums;
+mod errors;
+mod graphql;
+mod json_schema;
+mod keys;
+mod lenient;
+mod migrations;
+mod nested;
+mod optional;
+mod paths;
+mod proto;
+mod queries;
+mod random;
+mod serde;
+mod sqlite;
+mod typed_fields;
+mod typescript;
+mod values;
+mod widths;
+
 use chrono::NaiveDateTime;
 use reflected::Reflected;
 use rust_decimal::Decimal;
@@ -6,6 +32,7 @@ use rust_decimal::Decimal;
 struct CustomField;
 
 #[derive(Reflected, Clone, Default, PartialEq, Debug)]
+#[reflected(infer_keys)]
 pub struct User {
     id:   usize,
     name: String,
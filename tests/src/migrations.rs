@@ -0,0 +1,257 @@
+use reflected::Reflected;
+
+use crate::enums::OrderStatus;
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+#[reflected(rename = "Account")]
+pub struct AccountV1 {
+    #[reflected(id)]
+    id:       i64,
+    email:    String,
+    nickname: Option<String>,
+    age:      i32,
+    legacy:   bool,
+}
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+#[reflected(rename = "Account")]
+pub struct AccountV2 {
+    #[reflected(id)]
+    id:       i64,
+    email:    String,
+    nickname: String,
+    age:      i64,
+    #[reflected(enum)]
+    status:   OrderStatus,
+}
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+#[reflected(rename = "Account")]
+pub struct AccountV3 {
+    #[reflected(id)]
+    id:       i64,
+    email:    String,
+    nickname: String,
+    age:      i64,
+    #[reflected(enum)]
+    status:   OrderStatus,
+    score:    Option<f64>,
+}
+
+#[cfg(test)]
+mod test {
+    use reflected::{
+        schema::{MigrationStep, Schema},
+        sql::{sqlite::Repository, Dialect},
+        Error, Reflected,
+    };
+
+    use crate::{
+        migrations::{AccountV1, AccountV2, AccountV3},
+        User,
+    };
+
+    #[test]
+    fn snapshot() {
+        let schema = Schema::of::<User>();
+
+        assert_eq!(schema.name, "User");
+        assert_eq!(schema.primary_key(), vec!["id"]);
+        assert!(schema.column("custom").is_none());
+        assert!(schema.column("str_opt").unwrap().nullable);
+        assert_eq!(
+            schema.column("custom_id").unwrap().foreign_key.as_ref().unwrap().table,
+            "Custom"
+        );
+
+        let json = Schema::of::<AccountV2>().to_json();
+        assert_eq!(
+            json,
+            r#"{
+  "name": "Account",
+  "columns": [
+    {
+      "name": "id",
+      "type": "Integer",
+      "type_name": "i64",
+      "nullable": false,
+      "primary_key": true
+    },
+    {
+      "name": "email",
+      "type": "Text",
+      "type_name": "String",
+      "nullable": false,
+      "primary_key": false
+    },
+    {
+      "name": "nickname",
+      "type": "Text",
+      "type_name": "String",
+      "nullable": false,
+      "primary_key": false
+    },
+    {
+      "name": "age",
+      "type": "Integer",
+      "type_name": "i64",
+      "nullable": false,
+      "primary_key": false
+    },
+    {
+      "name": "status",
+      "type": "Enum",
+      "type_name": "OrderStatus",
+      "nullable": false,
+      "primary_key": false,
+      "variants": [
+        "Pending",
+        "Paid",
+        "Shipped"
+      ]
+    }
+  ]
+}"#
+        );
+
+        assert_eq!(Schema::from_json(&json), Ok(Schema::of::<AccountV2>()));
+        assert_eq!(
+            Schema::from_json(&Schema::of::<User>().to_json()),
+            Ok(Schema::of::<User>())
+        );
+
+        assert_eq!(
+            Schema::from_json(r#"{"name": "A", "columns": [{"name": "a"}]}"#),
+            Err(Error::InvalidSnapshot {
+                error: "missing `type`".into(),
+            })
+        );
+        assert_eq!(
+            Schema::from_json(r#"{"name": "A", "columns": [{"name": "a", "type": "Struct"}]}"#),
+            Err(Error::InvalidSnapshot {
+                error: "unknown column type `Struct`".into(),
+            })
+        );
+        assert!(Schema::from_json("{").is_err());
+    }
+
+    #[test]
+    fn diff() {
+        let migration = Schema::of::<AccountV1>().diff(&Schema::of::<AccountV2>());
+        let v2 = Schema::of::<AccountV2>();
+
+        assert_eq!(
+            migration.steps,
+            vec![
+                MigrationStep::DropColumn(Schema::of::<AccountV1>().column("legacy").unwrap().clone()),
+                MigrationStep::ChangeNullable(v2.column("nickname").unwrap().clone()),
+                MigrationStep::ChangeType(v2.column("age").unwrap().clone()),
+                MigrationStep::AddColumn(v2.column("status").unwrap().clone()),
+            ]
+        );
+
+        assert!(v2.diff(&v2).is_empty());
+    }
+
+    #[test]
+    fn postgres() {
+        let migration = Schema::of::<AccountV1>().diff(&Schema::of::<AccountV2>());
+
+        assert_eq!(
+            migration.to_sql(Dialect::Postgres),
+            vec![
+                r#"ALTER TABLE "Account" DROP COLUMN "legacy""#,
+                r#"UPDATE "Account" SET "nickname" = '' WHERE "nickname" IS NULL"#,
+                r#"ALTER TABLE "Account" ALTER COLUMN "nickname" SET NOT NULL"#,
+                r#"ALTER TABLE "Account" ALTER COLUMN "age" TYPE BIGINT USING "age"::BIGINT"#,
+                r#"ALTER TABLE "Account" ADD COLUMN "status" TEXT NOT NULL CONSTRAINT "Account_status_check" CHECK ("status" IN ('Pending', 'Paid', 'Shipped')) DEFAULT 'Pending'"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn enum_variants() {
+        let from = Schema::of::<AccountV2>();
+        let mut to = from.clone();
+        to.columns
+            .iter_mut()
+            .find(|a| a.name == "status")
+            .unwrap()
+            .variants
+            .push("Refunded".into());
+
+        assert_eq!(
+            from.diff(&to).to_sql(Dialect::Postgres),
+            vec![
+                r#"ALTER TABLE "Account" DROP CONSTRAINT IF EXISTS "Account_status_check""#,
+                r#"ALTER TABLE "Account" ALTER COLUMN "status" TYPE TEXT USING "status"::TEXT"#,
+                r#"ALTER TABLE "Account" ADD CONSTRAINT "Account_status_check" CHECK ("status" IN ('Pending', 'Paid', 'Shipped', 'Refunded'))"#,
+            ]
+        );
+
+        assert_eq!(
+            from.diff(&to).to_sql(Dialect::MySql),
+            vec![
+                "ALTER TABLE `Account` MODIFY COLUMN `status` ENUM('Pending', 'Paid', 'Shipped', \
+                 'Refunded') NOT NULL"
+            ]
+        );
+    }
+
+    #[test]
+    fn mysql() {
+        let mut from = Schema::of::<AccountV2>();
+        from.name = "accounts".into();
+
+        assert_eq!(
+            from.diff(&Schema::of::<AccountV3>()).to_sql(Dialect::MySql),
+            vec![
+                "RENAME TABLE `accounts` TO `Account`",
+                "ALTER TABLE `Account` ADD COLUMN `score` DOUBLE",
+            ]
+        );
+
+        assert_eq!(
+            Schema::of::<AccountV1>()
+                .diff(&Schema::of::<AccountV2>())
+                .to_sql(Dialect::MySql)[2],
+            "ALTER TABLE `Account` MODIFY COLUMN `nickname` TEXT NOT NULL"
+        );
+    }
+
+    #[test]
+    fn sqlite() {
+        let repository = Repository::open_in_memory().unwrap();
+        repository.create_table::<AccountV1>().unwrap();
+
+        let mut account = AccountV1::default();
+        account.set_value(AccountV1::FIELDS.id, "1".into());
+        account.set_value(AccountV1::FIELDS.email, "a@b.c".into());
+        repository.insert(&account).unwrap();
+
+        let migration = Schema::of::<AccountV1>().diff(&Schema::of::<AccountV2>());
+        let statements = migration.to_sql(Dialect::Sqlite);
+
+        assert!(statements[0].starts_with(r#"CREATE TABLE "Account__new" ("#));
+        assert_eq!(
+            statements[1],
+            r#"INSERT INTO "Account__new" ("id", "email", "nickname", "age", "status") SELECT "id", "email", COALESCE("nickname", ''), "age", 'Pending' FROM "Account""#
+        );
+
+        for statement in statements {
+            repository.connection().execute_batch(&statement).unwrap();
+        }
+
+        let accounts = repository.select_all::<AccountV2>().unwrap();
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].get_value(AccountV2::FIELDS.email), "a@b.c");
+        assert_eq!(accounts[0].get_value(AccountV2::FIELDS.nickname), "");
+        assert_eq!(accounts[0].get_value(AccountV2::FIELDS.status), "Pending");
+
+        let migration = Schema::of::<AccountV2>().diff(&Schema::of::<AccountV3>());
+        assert_eq!(
+            migration.to_sql(Dialect::Sqlite),
+            vec![r#"ALTER TABLE "Account" ADD COLUMN "score" REAL"#]
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use reflected::Reflected;
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+pub struct Coordinates {
+    lat: f64,
+    lon: f64,
+}
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+pub struct Address {
+    city:   String,
+    street: String,
+    #[reflected(struct)]
+    coords: Coordinates,
+}
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+pub struct Customer {
+    #[reflected(id)]
+    id:      usize,
+    name:    String,
+    #[reflected(struct)]
+    address: Address,
+}
+
+#[cfg(test)]
+mod test {
+    use reflected::{Error, Reflected, ReflectedEq};
+
+    use crate::nested::{Address, Customer};
+
+    #[test]
+    fn fields() {
+        assert!(Customer::FIELDS.address.is_struct());
+        assert!(!Customer::FIELDS.address.is_custom());
+        assert_eq!(Customer::FIELDS.address.type_name, "Address");
+        assert_eq!(Customer::simple_fields(), &[&Customer::FIELDS.name.field]);
+        assert_eq!(
+            Customer::column_fields(),
+            vec![&Customer::FIELDS.id.field, &Customer::FIELDS.name.field]
+        );
+
+        let customer = Customer::default();
+        let address = customer.nested(Customer::FIELDS.address).unwrap().unwrap();
+        assert_eq!(address.dyn_type_name(), "Address");
+        assert_eq!(address.dyn_field_names(), vec!["city", "street", "coords"]);
+
+        assert_eq!(
+            customer.nested(Customer::FIELDS.name).err(),
+            Some(Error::NotStruct { field: "name" })
+        );
+    }
+
+    #[test]
+    fn path() {
+        let mut customer = Customer::default();
+
+        customer.set_value_by_path("name", "peter".into());
+        customer.set_value_by_path("address.city", "Vilnius".into());
+        customer.set_value_by_path("address.coords.lat", "54.68".into());
+
+        assert_eq!(customer.name, "peter");
+        assert_eq!(customer.address.city, "Vilnius");
+        assert_eq!(customer.address.coords.lat, 54.68);
+
+        assert_eq!(customer.value_by_path("name"), "peter");
+        assert_eq!(customer.value_by_path("address.city"), "Vilnius");
+        assert_eq!(customer.value_by_path("address.coords.lat"), "54.68");
+        assert_eq!(customer.value_by_path("address.coords.lon"), "0.0");
+    }
+
+    #[test]
+    fn path_errors() {
+        let mut customer = Customer::default();
+
+        assert_eq!(
+            customer.try_value_by_path("address"),
+            Err(Error::NestedStruct { field: "address" })
+        );
+        assert_eq!(
+            customer.try_value_by_path("name.first"),
+            Err(Error::NotStruct { field: "name" })
+        );
+        assert_eq!(
+            customer.try_value_by_path("address.town"),
+            Err(Error::UnknownField {
+                type_name: "Address",
+                name:      "town".into(),
+            })
+        );
+        assert!(customer.try_set_value_by_path("address.coords.lat", "north".into()).is_err());
+        assert_eq!(customer, Customer::default());
+    }
+
+    #[test]
+    fn random() {
+        let customer = Customer::random();
+
+        assert_ne!(customer.address, Address::default());
+        assert_ne!(customer.address.city, "");
+
+        customer.assert_eq(&customer.clone());
+    }
+
+    #[test]
+    #[should_panic(expected = "Reflected eq error")]
+    fn reflected_eq() {
+        let customer = Customer::random();
+        let mut other = customer.clone();
+        other.address.city.push('!');
+
+        customer.assert_eq(&other);
+    }
+}
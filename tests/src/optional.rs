@@ -0,0 +1,147 @@
+use chrono::NaiveDateTime;
+use reflected::Reflected;
+use rust_decimal::Decimal;
+
+use crate::{enums::OrderStatus, nested::Coordinates};
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+pub struct Optionals {
+    int8:     Option<i8>,
+    int16:    Option<i16>,
+    int32:    Option<i32>,
+    int64:    Option<i64>,
+    int128:   Option<i128>,
+    intsize:  Option<isize>,
+    uint8:    Option<u8>,
+    uint16:   Option<u16>,
+    uint32:   Option<u32>,
+    uint64:   Option<u64>,
+    uint128:  Option<u128>,
+    usize:    Option<usize>,
+    float32:  Option<f32>,
+    float64:  Option<f64>,
+    letter:   Option<char>,
+    text:     Option<String>,
+    date:     Option<NaiveDateTime>,
+    decimal:  Option<Decimal>,
+    boolean:  Option<bool>,
+    #[reflected(enum)]
+    status:   Option<OrderStatus>,
+    #[reflected(struct)]
+    location: Option<Coordinates>,
+}
+
+#[cfg(test)]
+mod test {
+    use reflected::{Reflected, ReflectedEq};
+
+    use crate::optional::Optionals;
+
+    macro_rules! matrix {
+        ($($field:ident: $value:literal, $invalid:expr;)*) => {
+            $(
+                #[test]
+                fn $field() {
+                    let field = Optionals::FIELDS.$field;
+                    let mut data = Optionals::default();
+
+                    assert!(field.is_optional());
+                    assert!(!field.is_custom());
+                    assert_eq!(data.get_value(field), "NULL");
+
+                    data.set_value(field, Some($value));
+                    assert!(data.$field.is_some());
+                    assert_eq!(data.get_value(field), $value);
+                    data.assert_eq(&data.clone());
+
+                    if let Some(invalid) = $invalid {
+                        assert!(data.try_set_value(field, Some(invalid)).is_err());
+                        assert_eq!(data.get_value(field), $value);
+                    }
+
+                    data.set_value(field, None);
+                    assert_eq!(data.$field, None);
+                    assert_eq!(data.get_value(field), "NULL");
+                }
+            )*
+
+            #[test]
+            fn random() {
+                let mut some = Optionals::fields().iter().map(|_| false).collect::<Vec<_>>();
+                let mut none = some.clone();
+
+                for _ in 0..200 {
+                    let data = Optionals::random();
+
+                    let mut copy = Optionals::default();
+                    $(
+                        copy.set_value(Optionals::FIELDS.$field, data.$field.as_ref().map(|_| data.get_value(Optionals::FIELDS.$field)).as_deref());
+                    )*
+                    copy.location = data.location.clone();
+                    data.assert_eq(&copy);
+
+                    for (i, field) in Optionals::fields().iter().enumerate() {
+                        if field.is_struct() {
+                            continue;
+                        }
+                        if data.get_value(field) == "NULL" {
+                            none[i] = true;
+                        } else {
+                            some[i] = true;
+                        }
+                    }
+                }
+
+                assert!(some.iter().zip(&none).zip(Optionals::fields()).all(|((some, none), field)| field.is_struct() || *some && *none));
+            }
+        };
+    }
+
+    matrix! {
+        int8: "-5", Some("200");
+        int16: "-300", Some("40000");
+        int32: "-70000", Some("1.5");
+        int64: "-5000000000", Some("a");
+        int128: "-170141183460469231731687303715884105728", Some("");
+        intsize: "-12", Some("-");
+        uint8: "255", Some("-1");
+        uint16: "65535", Some("65536");
+        uint32: "4294967295", Some("4294967296");
+        uint64: "18446744073709551615", Some("18446744073709551616");
+        uint128: "340282366920938463463374607431768211455", Some("-1");
+        usize: "12", Some("1e3");
+        float32: "5.5", Some("five");
+        float64: "0.438297489", Some("1,5");
+        letter: "ž", Some("ab");
+        text: "NULL?", None::<&str>;
+        date: "2024-05-01 10:20:30.123456789", Some("2024-05-01");
+        decimal: "100.25", Some("100.25.1");
+        boolean: "1", Some("true");
+        status: "Shipped", Some("shipped");
+    }
+
+    #[test]
+    fn nested_struct() {
+        let mut data = Optionals::default();
+
+        assert!(Optionals::FIELDS.location.is_struct());
+        assert!(Optionals::FIELDS.location.is_optional());
+        assert!(data.nested(Optionals::FIELDS.location).unwrap().is_none());
+        assert_eq!(data.value_by_path("location.lat"), "NULL");
+        data.assert_eq(&data.clone());
+
+        data.set_value_by_path("location.lat", "54.5".into());
+        assert!(data.location.is_some());
+        assert_eq!(data.value_by_path("location.lon"), "0.0");
+        assert_eq!(data.value_by_path("location.lat"), "54.5");
+        data.assert_eq(&data.clone());
+    }
+
+    #[test]
+    #[should_panic(expected = "Left is null: false, Right is null: true")]
+    fn nested_struct_eq() {
+        let mut data = Optionals::default();
+        data.set_value_by_path("location.lat", "54.5".into());
+        data.assert_eq(&Optionals::default());
+    }
+}
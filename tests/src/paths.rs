@@ -0,0 +1,123 @@
+use reflected::Reflected;
+
+pub type UserId = u64;
+pub type Label = String;
+
+mod models {
+    use reflected::Reflected;
+
+    #[derive(Reflected, Clone, Copy, Default, PartialEq, Debug)]
+    pub enum Level {
+        #[default]
+        Low,
+        High,
+    }
+}
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+pub struct Qualified {
+    #[reflected(id, type = "u64")]
+    id:       UserId,
+    name:     std::string::String,
+    created:  chrono::NaiveDateTime,
+    cash:     rust_decimal::Decimal,
+    nickname: std::option::Option<std::string::String>,
+    bonus:    core::option::Option<rust_decimal::Decimal>,
+    #[reflected(type = "String")]
+    label:    Label,
+    #[reflected(enum)]
+    level:    models::Level,
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use proptest::{
+        strategy::{Strategy, ValueTree},
+        test_runner::TestRunner,
+    };
+    use quickcheck::Gen;
+    use reflected::{
+        schema::{graphql, proto, Schema},
+        sql::Dialect,
+        Reflected, ReflectedEq,
+    };
+    use rust_decimal::Decimal;
+
+    use crate::paths::{models::Level, Qualified};
+
+    #[test]
+    fn classification() {
+        assert!(Qualified::FIELDS.id.is_integer());
+        assert!(Qualified::FIELDS.name.is_text());
+        assert!(Qualified::FIELDS.created.is_date());
+        assert!(Qualified::FIELDS.cash.is_decimal());
+        assert!(Qualified::FIELDS.label.is_text());
+        assert!(Qualified::FIELDS.level.is_enum());
+
+        assert!(Qualified::FIELDS.nickname.is_text());
+        assert!(Qualified::FIELDS.nickname.is_optional());
+        assert!(Qualified::FIELDS.bonus.is_decimal());
+        assert!(Qualified::FIELDS.bonus.is_optional());
+
+        assert!(Qualified::fields().iter().all(|a| !a.is_custom()));
+    }
+
+    #[test]
+    fn type_names() {
+        assert_eq!(Qualified::FIELDS.id.type_name, "UserId");
+        assert_eq!(Qualified::FIELDS.name.type_name, "String");
+        assert_eq!(Qualified::FIELDS.created.type_name, "NaiveDateTime");
+        assert_eq!(Qualified::FIELDS.nickname.type_name, "String");
+        assert_eq!(Qualified::FIELDS.level.type_name, "Level");
+
+        assert_eq!(Qualified::FIELDS.id.type_override, Some("u64"));
+        assert_eq!(Qualified::FIELDS.id.value_type_name(), "u64");
+        assert_eq!(Qualified::FIELDS.name.value_type_name(), "String");
+    }
+
+    #[test]
+    fn type_override_width() {
+        assert_eq!(Schema::of::<Qualified>().column("id").unwrap().type_name, "u64");
+        assert_eq!(
+            Dialect::MySql.column_type(Qualified::FIELDS.id),
+            Some("BIGINT UNSIGNED".into())
+        );
+        assert!(proto::<Qualified>().contains("  uint64 id = 1;\n"));
+        assert!(graphql::<Qualified>().contains("  label: String!\n"));
+
+        let mut runner = TestRunner::deterministic();
+        let strategy = reflected::strategy::<Qualified>();
+        let ids: Vec<_> = (0..50).map(|_| strategy.new_tree(&mut runner).unwrap().current().id).collect();
+        assert!(ids.iter().any(|a| *a > u64::from(u8::MAX)));
+
+        let mut g = Gen::new(100);
+        let ids: Vec<_> = (0..50)
+            .map(|_| reflected::quickcheck::arbitrary::<Qualified>(&mut g).id)
+            .collect();
+        assert!(ids.iter().any(|a| *a > u64::from(u8::MAX)));
+    }
+
+    #[test]
+    fn get_set() {
+        let mut value = Qualified::default();
+
+        value.set_value(Qualified::FIELDS.id, "77".into());
+        value.set_value(Qualified::FIELDS.bonus, "1.5".into());
+        value.set_value(Qualified::FIELDS.level, "High".into());
+        value.set_value(Qualified::FIELDS.created, "2024-05-01 10:00:00".into());
+
+        assert_eq!(value.id, 77);
+        assert_eq!(value.bonus, Some(Decimal::from_str("1.5").unwrap()));
+        assert_eq!(value.level, Level::High);
+        assert_eq!(value.get_value(Qualified::FIELDS.created), "2024-05-01 10:00:00");
+        assert_eq!(value.get_value(Qualified::FIELDS.nickname), "NULL");
+    }
+
+    #[test]
+    fn random() {
+        let value = Qualified::random();
+        value.assert_eq(&value.clone());
+    }
+}
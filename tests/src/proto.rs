@@ -0,0 +1,117 @@
+use reflected::Reflected;
+
+use crate::enums::OrderStatus;
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+pub struct Payment {
+    #[reflected(id)]
+    id:       i64,
+    amount:   f64,
+    #[reflected(tag = 10)]
+    currency: String,
+    retries:  u8,
+    #[reflected(enum)]
+    status:   OrderStatus,
+    #[reflected(tag = 5)]
+    note:     Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use reflected::{schema::proto, Reflected};
+
+    use crate::{
+        json_schema::{Depot, Shipment},
+        proto::Payment,
+    };
+
+    #[test]
+    fn tags() {
+        let tags: Vec<_> = Payment::fields().iter().map(|a| a.tag).collect();
+        assert_eq!(tags, [1, 2, 10, 11, 12, 5]);
+    }
+
+    #[test]
+    fn messages() {
+        assert_eq!(
+            proto::<Payment>(),
+            r#"syntax = "proto3";
+
+enum OrderStatus {
+  ORDER_STATUS_PENDING = 0;
+  ORDER_STATUS_PAID = 1;
+  ORDER_STATUS_SHIPPED = 2;
+}
+
+message Payment {
+  int64 id = 1;
+  double amount = 2;
+  string currency = 10;
+  uint32 retries = 11;
+  OrderStatus status = 12;
+  optional string note = 5;
+}
+"#
+        );
+    }
+
+    #[test]
+    fn nested() {
+        assert_eq!(
+            proto::<Shipment>(),
+            r#"syntax = "proto3";
+
+import "google/protobuf/timestamp.proto";
+
+enum OrderStatus {
+  ORDER_STATUS_PENDING = 0;
+  ORDER_STATUS_PAID = 1;
+  ORDER_STATUS_SHIPPED = 2;
+}
+
+message Shipment {
+  uint64 id = 1;
+  float weight = 2;
+  int32 delta = 3;
+  string price = 4;
+  google.protobuf.Timestamp sent = 5;
+  bool express = 6;
+  optional string note = 7;
+  optional OrderStatus status = 8;
+  Address address = 9;
+  optional Coordinates tracking = 10;
+}
+
+message Address {
+  string city = 1;
+  string street = 2;
+  Coordinates coords = 3;
+}
+
+message Coordinates {
+  double lat = 1;
+  double lon = 2;
+}
+"#
+        );
+    }
+
+    #[test]
+    fn renamed_nested() {
+        assert_eq!(
+            proto::<Depot>(),
+            r#"syntax = "proto3";
+
+message Depot {
+  uint32 id = 1;
+  locations location = 2;
+  optional locations backup = 3;
+}
+
+message locations {
+  string city = 1;
+}
+"#
+        );
+    }
+}
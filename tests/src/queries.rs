@@ -0,0 +1,201 @@
+use reflected::Reflected;
+
+use crate::CustomField;
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+pub struct Membership {
+    #[reflected(id)]
+    user_id:  u32,
+    #[reflected(id)]
+    group_id: u32,
+}
+
+/// Has no columns at all
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+pub struct Draft {
+    custom: CustomField,
+}
+
+#[cfg(test)]
+mod test {
+    use reflected::{
+        sql::{
+            delete, delete_by, insert, insert_batch, insert_simple, select, select_by, update, Dialect, Query,
+        },
+        Error, Reflected, Value,
+    };
+
+    use crate::{
+        enums::Order,
+        keys::Shift,
+        nested::Coordinates,
+        queries::{Draft, Membership},
+    };
+
+    fn order(id: usize, status: &str) -> Order {
+        let mut order = Order::default();
+        order.set_val(Order::FIELDS.id, id);
+        order.set_val(Order::FIELDS.status, status);
+        order
+    }
+
+    #[test]
+    fn insert_values() {
+        let order = order(5, "Paid");
+
+        assert_eq!(
+            insert(Dialect::Postgres, &order),
+            Query {
+                sql:    r#"INSERT INTO "Order" ("id", "status", "previous_status") VALUES ($1, $2, $3)"#
+                    .into(),
+                params: vec![Value::UInt(5), Value::Text("Paid".into()), Value::Null],
+            }
+        );
+
+        assert_eq!(
+            insert_simple(Dialect::Sqlite, &order),
+            Query {
+                sql:    r#"INSERT INTO "Order" ("status", "previous_status") VALUES (?, ?)"#.into(),
+                params: vec![Value::Text("Paid".into()), Value::Null],
+            }
+        );
+    }
+
+    #[test]
+    fn insert_defaults() {
+        let membership = Membership::default();
+
+        assert_eq!(
+            insert_simple(Dialect::Sqlite, &membership).sql,
+            r#"INSERT INTO "Membership" DEFAULT VALUES"#
+        );
+        assert_eq!(
+            insert_simple(Dialect::MySql, &membership).sql,
+            "INSERT INTO `Membership` () VALUES ()"
+        );
+
+        assert_eq!(
+            insert_batch(Dialect::Postgres, &[Draft::default()]).unwrap().sql,
+            r#"INSERT INTO "Draft" DEFAULT VALUES"#
+        );
+        assert_eq!(
+            insert_batch(Dialect::Postgres, &[Draft::default(), Draft::default()]),
+            Err(Error::NothingToInsert { type_name: "Draft" })
+        );
+        assert_eq!(
+            insert_batch(Dialect::MySql, &[Draft::default(), Draft::default()]).unwrap().sql,
+            "INSERT INTO `Draft` () VALUES (), ()"
+        );
+    }
+
+    #[test]
+    fn injection() {
+        let query = select_by(Dialect::MySql, Order::FIELDS.status, "'; DROP TABLE `Order`; --");
+
+        assert_eq!(
+            query.sql,
+            "SELECT `id`, `status`, `previous_status` FROM `Order` WHERE `status` = ?"
+        );
+        assert_eq!(
+            query.params,
+            vec![Value::Text("'; DROP TABLE `Order`; --".into())]
+        );
+    }
+
+    #[test]
+    fn batch() {
+        let orders = [order(1, "Paid"), order(2, "Shipped")];
+
+        assert_eq!(
+            insert_batch(Dialect::Postgres, &orders),
+            Ok(Query {
+                sql:    r#"INSERT INTO "Order" ("id", "status", "previous_status") VALUES ($1, $2, $3), ($4, $5, $6)"#
+                    .into(),
+                params: vec![
+                    Value::UInt(1),
+                    Value::Text("Paid".into()),
+                    Value::Null,
+                    Value::UInt(2),
+                    Value::Text("Shipped".into()),
+                    Value::Null,
+                ],
+            })
+        );
+
+        assert_eq!(
+            insert_batch::<Order>(Dialect::Sqlite, &[]),
+            Err(Error::EmptyBatch)
+        );
+    }
+
+    #[test]
+    fn update_by_primary_key() {
+        let mut shift = Shift::default();
+        shift.set_val(Shift::FIELDS.worker_id, 3);
+        shift.set_val(Shift::FIELDS.day, 7);
+        shift.set_val(Shift::FIELDS.hours, 8.5);
+
+        assert_eq!(
+            update(Dialect::Postgres, &shift),
+            Ok(Query {
+                sql:    r#"UPDATE "Shift" SET "manager" = $1, "site" = $2, "hours" = $3 WHERE "worker_id" = $4 AND "day" = $5"#
+                    .into(),
+                params: vec![
+                    Value::UInt(0),
+                    Value::UInt(0),
+                    Value::Float(8.5),
+                    Value::UInt(3),
+                    Value::UInt(7),
+                ],
+            })
+        );
+
+        assert_eq!(
+            update(Dialect::Sqlite, &Coordinates::default()),
+            Err(Error::NoPrimaryKey {
+                type_name: "Coordinates",
+            })
+        );
+
+        assert_eq!(
+            update(Dialect::Sqlite, &Membership::default()),
+            Err(Error::NothingToUpdate {
+                type_name: "Membership",
+            })
+        );
+    }
+
+    #[test]
+    fn select_and_delete() {
+        assert_eq!(
+            select::<Order>(Dialect::Sqlite).sql,
+            r#"SELECT "id", "status", "previous_status" FROM "Order""#
+        );
+
+        assert_eq!(
+            select_by(Dialect::Postgres, Order::FIELDS.previous_status, None::<&str>),
+            Query {
+                sql:
+                    r#"SELECT "id", "status", "previous_status" FROM "Order" WHERE "previous_status" IS NULL"#
+                        .into(),
+                params: vec![],
+            }
+        );
+
+        assert_eq!(
+            delete(Dialect::Postgres, &order(4, "Paid")),
+            Ok(Query {
+                sql:    r#"DELETE FROM "Order" WHERE "id" = $1"#.into(),
+                params: vec![Value::UInt(4)],
+            })
+        );
+
+        assert_eq!(
+            delete_by(Dialect::MySql, Order::FIELDS.status, "Pending"),
+            Query {
+                sql:    "DELETE FROM `Order` WHERE `status` = ?".into(),
+                params: vec![Value::Text("Pending".into())],
+            }
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use chrono::NaiveDateTime;
+use reflected::{rand::Rng, Reflected};
+use rust_decimal::Decimal;
+
+use crate::{paths::Label, CustomField};
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+pub struct Person {
+    #[reflected(random(range = 18..99))]
+    age:        u8,
+    #[reflected(random(range = 1.5..=2.5))]
+    height:     f64,
+    #[reflected(random(range = Decimal::ONE..Decimal::TEN))]
+    balance:    Decimal,
+    #[reflected(random(len = 3..=5))]
+    code:       String,
+    #[reflected(random(len = 10..11))]
+    note:       Option<String>,
+    #[reflected(random(date_range = "2020-01-01".."2021-01-01"))]
+    registered: NaiveDateTime,
+    #[reflected(random(with = even))]
+    lucky:      u32,
+    #[reflected(random(with = custom))]
+    custom:     CustomField,
+    first_name: String,
+    last_name:  String,
+    name:       String,
+    email:      String,
+    website:    Option<String>,
+    phone:      String,
+    nickname:   String,
+    #[reflected(type = "String", random(len = 6..7))]
+    sku:        Label,
+    #[reflected(type = "String")]
+    work_email: Label,
+}
+
+/// `date_range` bounds are parsed when generating
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+pub struct InvalidDate {
+    #[reflected(random(date_range = "2020-13-01".."2025-01-01"))]
+    created: NaiveDateTime,
+}
+
+fn even(rng: &mut impl Rng) -> u32 {
+    rng.gen_range(0..100) * 2
+}
+
+fn custom(_: &mut impl Rng) -> CustomField {
+    CustomField
+}
+
+#[cfg(test)]
+mod test {
+    use reflected::{
+        rand::{rngs::StdRng, SeedableRng},
+        random::Faker,
+        RandomValue, Reflected, ReflectedEq,
+    };
+    use rust_decimal::Decimal;
+
+    use crate::{
+        enums::Order,
+        json_schema::Shipment,
+        random::{InvalidDate, Person},
+        User,
+    };
+
+    #[test]
+    fn seeded() {
+        User::random_seeded(42).assert_eq(&User::random_seeded(42));
+        Shipment::random_seeded(42).assert_eq(&Shipment::random_seeded(42));
+
+        assert_ne!(User::random_seeded(1), User::random_seeded(2));
+    }
+
+    #[test]
+    fn with_rng() {
+        let mut a = StdRng::seed_from_u64(7);
+        let mut b = StdRng::seed_from_u64(7);
+
+        let orders: Vec<_> = (0..10).map(|_| Order::random_with(&mut a)).collect();
+        let same: Vec<_> = (0..10).map(|_| Order::random_with(&mut b)).collect();
+
+        assert_eq!(orders, same);
+        assert_eq!(
+            Option::<u32>::random_value_with(&mut a),
+            Option::<u32>::random_value_with(&mut b)
+        );
+    }
+
+    #[test]
+    fn nested_optional() {
+        let shipments: Vec<_> = (0..50).map(Shipment::random_seeded).collect();
+
+        assert!(shipments.iter().any(|a| a.value_by_name("note") == "NULL"));
+        assert!(shipments.iter().any(|a| a.nested(Shipment::FIELDS.tracking).unwrap().is_none()));
+        assert!(shipments.iter().any(|a| a.nested(Shipment::FIELDS.tracking).unwrap().is_some()));
+    }
+
+    #[test]
+    fn dates() {
+        for seed in 0..50 {
+            let date = User::random_seeded(seed).get_value(User::FIELDS.birthday);
+            assert!(("2000".."2030").contains(&&date[..4]), "{date}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid `date_range` bound")]
+    fn invalid_date() {
+        InvalidDate::random();
+    }
+
+    #[test]
+    fn attributes() {
+        for seed in 0..200 {
+            let person = Person::random_seeded(seed);
+
+            assert!((18..99).contains(&person.age));
+            assert!((1.5..=2.5).contains(&person.height));
+            assert!(person.balance >= Decimal::ONE && person.balance < Decimal::TEN);
+            assert!((3..=5).contains(&person.code.len()));
+            assert!(person.note.as_ref().map_or(true, |a| a.len() == 10));
+            assert_eq!(&person.registered.to_string()[..4], "2020");
+            assert_eq!(person.lucky % 2, 0);
+            assert_eq!(person.sku.len(), 6);
+        }
+    }
+
+    #[test]
+    fn fakers() {
+        let person = Person::random_seeded(5);
+
+        assert!(person.first_name.chars().all(char::is_alphabetic));
+        assert!(person.last_name.chars().all(char::is_alphabetic));
+        assert_eq!(person.name.split(' ').count(), 2);
+        assert!(person.email.ends_with("@example.com"));
+        assert!(person.work_email.ends_with("@example.com"));
+        assert!(person.phone.starts_with("+1-555-"));
+        assert!(person.nickname.chars().next().unwrap().is_lowercase());
+
+        let websites: Vec<_> = (0..20).filter_map(|seed| Person::random_seeded(seed).website).collect();
+        assert!(!websites.is_empty());
+        assert!(websites.iter().all(|a| a.starts_with("https://example.com/")));
+
+        assert_eq!(Faker::for_field("userEmail"), Some(Faker::Email));
+        assert_eq!(Faker::for_field("FIRST-NAME"), Some(Faker::FirstName));
+        assert_eq!(Faker::for_field("avatar_url"), Some(Faker::Url));
+        assert_eq!(Faker::for_field("file_name"), None);
+    }
+
+    #[test]
+    fn floats() {
+        let heights: Vec<f64> = (0..20).map(|seed| User::random_seeded(seed).height).collect();
+        assert!(heights.iter().any(|a| a.fract() != 0.0));
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use chrono::NaiveDateTime;
+    use reflected::{serde::AsSerde, Reflected, ReflectedEq};
+    use rust_decimal::Decimal;
+    use serde_json::json;
+
+    use crate::{nested::Customer, User};
+
+    fn user() -> User {
+        User {
+            id: 5,
+            name: "Bob".into(),
+            birthday: NaiveDateTime::from_str("2024-05-01T10:20:30.5").unwrap(),
+            age: 30,
+            cash: Decimal::new(10025, 2),
+            is_poros: true,
+            height: 1.5,
+            usize_opt: Some(7),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn serialize() {
+        let json = serde_json::to_value(AsSerde(user())).unwrap();
+
+        assert_eq!(
+            json,
+            json!({
+                "id": 5,
+                "name": "Bob",
+                "birthday": "2024-05-01T10:20:30.500",
+                "age": 30,
+                "custom_id": 0,
+                "cash": "100.25",
+                "is_poros": true,
+                "height": 1.5,
+                "str_opt": null,
+                "usize_opt": 7,
+                "bool_opt": null,
+                "decimal_opt": null,
+            })
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let user = User::random();
+
+        let json = serde_json::to_string(&AsSerde(user.clone())).unwrap();
+        let parsed: AsSerde<User> = serde_json::from_str(&json).unwrap();
+
+        user.assert_eq(&parsed);
+    }
+
+    #[test]
+    fn deserialize() {
+        let user: AsSerde<User> = serde_json::from_value(json!({
+            "name": "Alice",
+            "birthday": "2024-05-01T10:20:30Z",
+            "age": 20,
+            "height": 2,
+            "bool_opt": false,
+            "unknown": [1, 2, 3],
+        }))
+        .unwrap();
+
+        assert_eq!(user.get_value(User::FIELDS.name), "Alice");
+        assert_eq!(user.get_value(User::FIELDS.birthday), "2024-05-01 10:20:30");
+        assert_eq!(user.get_value(User::FIELDS.age), "20");
+        assert_eq!(user.get_value(User::FIELDS.height), "2.0");
+        assert_eq!(user.get_value(User::FIELDS.bool_opt), "0");
+        assert_eq!(user.get_value(User::FIELDS.str_opt), "NULL");
+        assert_eq!(user.get_value(User::FIELDS.id), "0");
+    }
+
+    #[test]
+    fn errors() {
+        let error = serde_json::from_value::<AsSerde<User>>(json!({ "age": "old" })).unwrap_err();
+        assert!(error.to_string().contains("age"), "{error}");
+
+        let error = serde_json::from_value::<AsSerde<User>>(json!({ "name": null })).unwrap_err();
+        assert!(error.to_string().contains("name"), "{error}");
+
+        assert!(serde_json::from_value::<AsSerde<User>>(json!([1, 2])).is_err());
+    }
+
+    #[test]
+    fn nested() {
+        let mut customer = Customer::default();
+        customer.set_value_by_path("name", Some("Bob"));
+        customer.set_value_by_path("address.city", Some("Oslo"));
+        customer.set_value_by_path("address.coords.lat", Some("59.9"));
+
+        let json = serde_json::to_value(AsSerde(customer.clone())).unwrap();
+
+        assert_eq!(json["address"]["city"], "Oslo");
+        assert_eq!(json["address"]["coords"]["lat"], 59.9);
+
+        let parsed: AsSerde<Customer> = serde_json::from_value(json).unwrap();
+        customer.assert_eq(&parsed);
+    }
+}
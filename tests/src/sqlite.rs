@@ -0,0 +1,180 @@
+use reflected::Reflected;
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+pub struct Author {
+    #[reflected(id)]
+    id:   i64,
+    name: String,
+}
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+pub struct Book {
+    #[reflected(id)]
+    id:     i64,
+    #[reflected(foreign_key = "Author")]
+    author: i64,
+    title:  String,
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use chrono::NaiveDateTime;
+    use reflected::{
+        sql::sqlite::{rusqlite, Error, Repository},
+        Reflected, ReflectedEq,
+    };
+    use rust_decimal::Decimal;
+
+    use crate::{
+        enums::Order,
+        keys::Shift,
+        sqlite::{Author, Book},
+        User,
+    };
+
+    /// Fixture types reference tables which don't exist
+    fn repository() -> Repository {
+        let repository = Repository::open_in_memory().unwrap();
+        repository.connection().execute_batch("PRAGMA foreign_keys = OFF").unwrap();
+        repository.create_table::<User>().unwrap();
+        repository.create_table::<Order>().unwrap();
+        repository.create_table::<Shift>().unwrap();
+        repository
+    }
+
+    fn user(id: usize) -> User {
+        let mut user = User::random();
+        user.id = id;
+        user.custom_id = 0;
+        user.birthday =
+            NaiveDateTime::parse_from_str("2024-05-01 10:20:30.123456789", "%Y-%m-%d %H:%M:%S%.9f").unwrap();
+        user
+    }
+
+    #[test]
+    fn insert_and_select() {
+        let repository = repository();
+
+        let users = [user(1), user(2)];
+        for user in &users {
+            repository.insert(user).unwrap();
+        }
+
+        let loaded = repository.select_all::<User>().unwrap();
+        assert_eq!(loaded.len(), 2);
+        for (user, loaded) in users.iter().zip(&loaded) {
+            user.assert_eq(loaded);
+        }
+    }
+
+    #[test]
+    fn find_by() {
+        let mut repository = repository();
+
+        let mut users = [user(1), user(2), user(3)];
+        users[0].str_opt = Some("text".into());
+        users[1].str_opt = None;
+        users[2].str_opt = Some("NULL".into());
+        repository.insert_batch(&users).unwrap();
+
+        let found = repository.find_by(User::FIELDS.id, 2).unwrap();
+        assert_eq!(found.len(), 1);
+        found[0].assert_eq(&users[1]);
+
+        let found = repository.find_by(User::FIELDS.str_opt, None::<String>).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, 2);
+
+        let found = repository.find_by(User::FIELDS.str_opt, "NULL").unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, 3);
+    }
+
+    #[test]
+    fn update_and_delete() {
+        let repository = repository();
+
+        let mut order = Order::default();
+        order.set_value(Order::FIELDS.id, "1".into());
+        repository.insert(&order).unwrap();
+
+        order.set_value(Order::FIELDS.status, "Shipped".into());
+        order.set_value(Order::FIELDS.previous_status, "Paid".into());
+        assert_eq!(repository.update(&order).unwrap(), 1);
+
+        let loaded = repository.select_all::<Order>().unwrap();
+        assert_eq!(loaded, vec![order.clone()]);
+
+        assert_eq!(repository.delete(&order).unwrap(), 1);
+        assert_eq!(repository.delete(&order).unwrap(), 0);
+        assert!(repository.select_all::<Order>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn composite_key() {
+        let repository = repository();
+
+        let mut shift = Shift::default();
+        shift.set_value(Shift::FIELDS.worker_id, "3".into());
+        shift.set_value(Shift::FIELDS.day, "7".into());
+        repository.insert(&shift).unwrap();
+
+        assert!(matches!(repository.insert(&shift), Err(Error::Sqlite(_))));
+
+        shift.set_value(Shift::FIELDS.hours, "8.5".into());
+        assert_eq!(repository.update(&shift).unwrap(), 1);
+        assert_eq!(repository.select_all::<Shift>().unwrap(), vec![shift]);
+    }
+
+    #[test]
+    fn large_numbers() {
+        let repository = repository();
+
+        let mut user = user(1);
+        user.age = i64::MAX as usize;
+        repository.insert(&user).unwrap();
+        repository.find_by(User::FIELDS.age, i64::MAX).unwrap()[0].assert_eq(&user);
+
+        user.id = 2;
+        user.usize_opt = Some(usize::MAX);
+        assert!(matches!(
+            repository.insert(&user),
+            Err(Error::Sqlite(rusqlite::Error::ToSqlConversionFailure(_)))
+        ));
+    }
+
+    #[test]
+    fn decimals() {
+        let repository = repository();
+
+        let mut user = user(1);
+        user.cash = Decimal::from_str("12345678901234567.891").unwrap();
+        user.decimal_opt = Some(Decimal::from_str("-0.000000000000000000000000001").unwrap());
+        repository.insert(&user).unwrap();
+
+        let users = repository.select_all::<User>().unwrap();
+        assert_eq!(users[0].cash.to_string(), "12345678901234567.891");
+        users[0].assert_eq(&user);
+    }
+
+    #[test]
+    fn foreign_keys() {
+        let repository = Repository::open_in_memory().unwrap();
+        repository.connection().execute_batch("PRAGMA foreign_keys = ON").unwrap();
+        repository.create_table::<Author>().unwrap();
+        repository.create_table::<Book>().unwrap();
+
+        let mut book = Book::default();
+        book.set_value(Book::FIELDS.author, "1".into());
+        assert!(matches!(repository.insert(&book), Err(Error::Sqlite(_))));
+
+        let mut author = Author::default();
+        author.set_value(Author::FIELDS.id, "1".into());
+        repository.insert(&author).unwrap();
+        repository.insert(&book).unwrap();
+
+        assert_eq!(repository.find_by(Book::FIELDS.author, 1).unwrap(), vec![book]);
+    }
+}
@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use reflected::{Field, Reflected};
+
+    use crate::User;
+
+    #[test]
+    fn get_set() {
+        let mut user = User::default();
+
+        assert_eq!(User::FIELDS.age.get(&user), &0);
+
+        User::FIELDS.age.set(&mut user, 25);
+        assert_eq!(user.age, 25);
+        assert_eq!(User::FIELDS.age.get(&user), &25);
+
+        *User::FIELDS.age.get_mut(&mut user) += 1;
+        assert_eq!(user.age, 26);
+
+        User::FIELDS.name.get_mut(&mut user).push_str("peter");
+        assert_eq!(User::FIELDS.name.get(&user), "peter");
+
+        User::FIELDS.str_opt.set(&mut user, Some("parker".into()));
+        assert_eq!(User::FIELDS.str_opt.get(&user).as_deref(), Some("parker"));
+        assert_eq!(user.get_value(User::FIELDS.str_opt), "parker");
+    }
+
+    #[test]
+    fn untyped_interop() {
+        let field: &'static Field<User> = User::FIELDS.age;
+        assert_eq!(field, User::field_by_name("age"));
+        assert_eq!(field.name, User::FIELDS.age.name);
+
+        let mut map = HashMap::<&'static Field<User>, usize>::default();
+        map.insert(User::FIELDS.age, 5);
+        assert_eq!(map[User::field_by_name("age")], 5);
+    }
+}
@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod test {
+    use reflected::schema::{typescript, TypeScript};
+
+    use crate::{
+        enums::Order,
+        json_schema::{Depot, Shipment},
+        nested::Customer,
+    };
+
+    #[test]
+    fn interfaces() {
+        assert_eq!(
+            typescript::<Shipment>(),
+            r#"// Generated from Reflected types, do not edit
+
+export type Decimal = string;
+
+/** ISO 8601 without offset, UTC */
+export type DateTime = string;
+
+export type OrderStatus = "Pending" | "Paid" | "Shipped";
+
+export interface Shipment {
+  id: number;
+  weight: number;
+  delta: number;
+  price: Decimal;
+  sent: DateTime;
+  express: boolean;
+  note: string | null;
+  status: OrderStatus | null;
+  address: Address;
+  tracking: Coordinates | null;
+}
+
+export interface Address {
+  city: string;
+  street: string;
+  coords: Coordinates;
+}
+
+export interface Coordinates {
+  lat: number;
+  lon: number;
+}
+"#
+        );
+    }
+
+    #[test]
+    fn multiple_types() {
+        let ts = TypeScript::new().add::<Customer>().add::<Order>().add::<Shipment>().to_string();
+
+        assert_eq!(ts.matches("export interface Address").count(), 1);
+        assert_eq!(ts.matches("export type OrderStatus").count(), 1);
+
+        let interfaces: Vec<_> = ts.lines().filter_map(|a| a.strip_prefix("export interface ")).collect();
+        assert_eq!(
+            interfaces,
+            [
+                "Customer {",
+                "Address {",
+                "Coordinates {",
+                "Order {",
+                "Shipment {"
+            ]
+        );
+
+        assert!(!typescript::<Order>().contains("Decimal"));
+    }
+
+    #[test]
+    fn write_to() {
+        let path = std::env::temp_dir().join(format!("reflected_{}.ts", std::process::id()));
+        let ts = TypeScript::new().add::<Order>();
+
+        ts.write_to(&path).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), ts.to_string());
+
+        let modified = std::fs::metadata(&path).unwrap().modified().unwrap();
+        ts.write_to(&path).unwrap();
+        assert_eq!(std::fs::metadata(&path).unwrap().modified().unwrap(), modified);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn renamed_nested() {
+        let ts = typescript::<Depot>();
+
+        assert!(ts.contains("  location: locations;\n  backup: locations | null;\n"));
+        assert!(ts.contains("export interface locations {"));
+        assert!(!ts.contains("Location"));
+    }
+}
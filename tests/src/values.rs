@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use chrono::NaiveDateTime;
+    use reflected::{Error, Reflected, Value};
+    use rust_decimal::Decimal;
+
+    use crate::{
+        enums::{Order, OrderStatus},
+        User,
+    };
+
+    #[test]
+    fn get() {
+        let mut user = User {
+            name: "NULL".into(),
+            age: 30,
+            height: 1.5,
+            is_poros: true,
+            ..Default::default()
+        };
+
+        assert_eq!(user.get_val(User::FIELDS.name), Value::Text("NULL".into()));
+        assert_eq!(user.get_val(User::FIELDS.age), Value::UInt(30));
+        assert_eq!(user.get_val(User::FIELDS.height), Value::Float(1.5));
+        assert_eq!(user.get_val(User::FIELDS.is_poros), Value::Bool(true));
+        assert_eq!(
+            user.get_val(User::FIELDS.cash),
+            Value::Decimal(Decimal::default())
+        );
+        assert_eq!(user.get_val(User::FIELDS.str_opt), Value::Null);
+
+        user.str_opt = Some("NULL".into());
+        assert_eq!(user.get_val(User::FIELDS.str_opt), Value::Text("NULL".into()));
+        assert_eq!(user.get_value(User::FIELDS.str_opt), "NULL");
+
+        assert_eq!(
+            user.try_get_val(User::FIELDS.custom),
+            Err(Error::UnsupportedCustom { field: "custom" })
+        );
+    }
+
+    #[test]
+    fn set() {
+        let mut user = User::default();
+        let date = NaiveDateTime::from_str("2024-05-01T10:20:30").unwrap();
+
+        user.set_val(User::FIELDS.age, 42);
+        user.set_val(User::FIELDS.height, 1.8);
+        user.set_val(User::FIELDS.is_poros, true);
+        user.set_val(User::FIELDS.birthday, date);
+        user.set_val(User::FIELDS.cash, Value::Int(100));
+        user.set_val(User::FIELDS.str_opt, "NULL");
+        user.set_val(User::FIELDS.decimal_opt, Some(Decimal::new(105, 1)));
+
+        assert_eq!(user.age, 42);
+        assert_eq!(user.height, 1.8);
+        assert!(user.is_poros);
+        assert_eq!(user.birthday, date);
+        assert_eq!(user.cash, Decimal::from(100));
+        assert_eq!(user.str_opt, Some("NULL".into()));
+        assert_eq!(user.decimal_opt, Some(Decimal::new(105, 1)));
+
+        user.set_val(User::FIELDS.str_opt, Value::Null);
+        user.set_val(User::FIELDS.decimal_opt, None::<Decimal>);
+        assert_eq!(user.str_opt, None);
+        assert_eq!(user.decimal_opt, None);
+    }
+
+    #[test]
+    fn enums() {
+        let mut order = Order::default();
+
+        assert_eq!(order.get_val(Order::FIELDS.status), Value::Text("Pending".into()));
+        assert_eq!(order.get_val(Order::FIELDS.previous_status), Value::Null);
+
+        order.set_val(Order::FIELDS.status, "Paid");
+        order.set_val(Order::FIELDS.previous_status, "Shipped");
+        assert_eq!(*Order::FIELDS.status.get(&order), OrderStatus::Paid);
+        assert_eq!(
+            *Order::FIELDS.previous_status.get(&order),
+            Some(OrderStatus::Shipped)
+        );
+
+        assert!(order.try_set_val(Order::FIELDS.status, Value::Int(1)).is_err());
+    }
+
+    #[test]
+    fn errors() {
+        let mut user = User::default();
+
+        assert_eq!(
+            user.try_set_val(User::FIELDS.age, Value::Null),
+            Err(Error::NullNotOptional { field: "age" })
+        );
+        assert_eq!(
+            user.try_set_val(User::FIELDS.age, Value::Int(-1)),
+            Err(Error::parse(
+                "age",
+                "-1",
+                "out of range integral type conversion attempted"
+            ))
+        );
+        assert_eq!(
+            user.try_set_val(User::FIELDS.name, Value::Bool(true)),
+            Err(Error::parse("name", "1", "expected text, got Bool"))
+        );
+        assert!(user.try_set_val(User::FIELDS.birthday, Value::Float(1.0)).is_err());
+        assert!(user.try_set_val(User::FIELDS.is_poros, Value::Int(2)).is_err());
+
+        assert_eq!(user, User::default());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Value::Null.to_string(), "NULL");
+        assert_eq!(Value::Bool(false).to_string(), "0");
+        assert_eq!(Value::Float(5.0).to_string(), "5.0");
+        assert_eq!(Value::from(0.1f32), Value::Float(0.1));
+        assert_eq!(Value::from('a'), Value::Text("a".into()));
+        assert_eq!(Value::from(None::<i32>), Value::Null);
+        assert_eq!(Value::from(-5i8), Value::Int(-5));
+        assert!(Value::Null.is_null());
+        assert_eq!(Value::Text("a".into()).as_str(), Some("a"));
+    }
+}
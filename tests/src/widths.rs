@@ -0,0 +1,83 @@
+use reflected::Reflected;
+
+#[derive(Reflected, Clone, Default, PartialEq, Debug)]
+pub struct Widths {
+    int8:    i8,
+    int16:   i16,
+    int32:   i32,
+    int64:   i64,
+    int128:  i128,
+    intsize: isize,
+    uint8:   u8,
+    uint16:  u16,
+    uint32:  u32,
+    uint64:  u64,
+    uint128: u128,
+    usize:   usize,
+    float32: f32,
+    float64: f64,
+    letter:  char,
+}
+
+#[cfg(test)]
+mod test {
+    use reflected::{Reflected, ReflectedEq};
+
+    use crate::widths::Widths;
+
+    #[test]
+    fn fields() {
+        for field in Widths::fields() {
+            assert!(!field.is_custom(), "{field:?}");
+        }
+
+        assert!(Widths::FIELDS.uint8.is_integer());
+        assert!(Widths::FIELDS.int128.is_integer());
+        assert!(Widths::FIELDS.uint128.is_integer());
+        assert!(Widths::FIELDS.float32.is_float());
+        assert!(Widths::FIELDS.letter.is_text());
+        assert_eq!(Widths::FIELDS.letter.type_name, "char");
+    }
+
+    #[test]
+    fn limits() {
+        let mut widths = Widths::default();
+
+        widths.set_value(Widths::FIELDS.int8, "-128".into());
+        widths.set_value(Widths::FIELDS.uint8, "255".into());
+        widths.set_value(Widths::FIELDS.int16, "-32768".into());
+        widths.set_value(Widths::FIELDS.uint16, "65535".into());
+        widths.set_value(Widths::FIELDS.int128, Some(&i128::MIN.to_string()));
+        widths.set_value(Widths::FIELDS.uint128, Some(&u128::MAX.to_string()));
+        widths.set_value(Widths::FIELDS.letter, "ž".into());
+
+        assert_eq!(widths.int8, i8::MIN);
+        assert_eq!(widths.uint8, u8::MAX);
+        assert_eq!(widths.int16, i16::MIN);
+        assert_eq!(widths.uint16, u16::MAX);
+        assert_eq!(widths.int128, i128::MIN);
+        assert_eq!(widths.uint128, u128::MAX);
+        assert_eq!(widths.letter, 'ž');
+
+        assert_eq!(widths.get_value(Widths::FIELDS.uint128), u128::MAX.to_string());
+        assert_eq!(widths.get_value(Widths::FIELDS.letter), "ž");
+
+        assert!(widths.try_set_value(Widths::FIELDS.uint8, "256".into()).is_err());
+        assert!(widths.try_set_value(Widths::FIELDS.int8, "-129".into()).is_err());
+        assert!(widths.try_set_value(Widths::FIELDS.letter, "ab".into()).is_err());
+    }
+
+    #[test]
+    fn random() {
+        for _ in 0..100 {
+            let widths = Widths::random();
+
+            let mut copy = Widths::default();
+            for field in Widths::fields() {
+                copy.set_value(field, Some(&widths.get_value(field)));
+            }
+
+            widths.assert_eq(&copy);
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use reflected::Reflected;
+
+#[derive(Reflected, Default)]
+#[reflected(arbitrary)]
+enum Status {
+    #[default]
+    Active,
+    Blocked,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use reflected::Reflected;
+
+#[derive(Reflected, Default)]
+struct User {
+    scores: Option<[u8; 4]>,
+}
+
+fn main() {}
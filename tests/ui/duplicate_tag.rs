@@ -0,0 +1,10 @@
+use reflected::Reflected;
+
+#[derive(Reflected, Default)]
+struct User {
+    id:   usize,
+    #[reflected(tag = 1)]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use reflected::Reflected;
+
+#[derive(Reflected)]
+enum Never {}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use reflected::Reflected;
+
+#[derive(Reflected)]
+enum Shape {
+    Empty,
+    Circle { radius: f64 },
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use reflected::Reflected;
+
+#[derive(Reflected, Default)]
+struct Wrapper<T> {
+    value: T,
+}
+
+fn main() {}
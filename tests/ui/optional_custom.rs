@@ -0,0 +1,11 @@
+use reflected::Reflected;
+
+#[derive(Default)]
+struct Avatar;
+
+#[derive(Reflected, Default)]
+struct User {
+    avatar: Option<Avatar>,
+}
+
+fn main() {}
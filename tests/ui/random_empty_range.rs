@@ -0,0 +1,21 @@
+use reflected::Reflected;
+
+#[derive(Reflected, Default)]
+struct Reversed {
+    #[reflected(random(range = 99..18))]
+    age: u8,
+}
+
+#[derive(Reflected, Default)]
+struct Empty {
+    #[reflected(random(len = 5..5))]
+    code: String,
+}
+
+#[derive(Reflected, Default)]
+struct Negative {
+    #[reflected(random(range = -1.5..=-2.5))]
+    offset: f64,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use reflected::Reflected;
+
+#[derive(Reflected, Default)]
+struct User {
+    #[reflected(random(range = 1..10))]
+    name: String,
+}
+
+fn main() {}
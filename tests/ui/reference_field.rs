@@ -0,0 +1,8 @@
+use reflected::Reflected;
+
+#[derive(Reflected, Default)]
+struct User {
+    name: &'static str,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use reflected::Reflected;
+
+#[derive(Reflected, Default)]
+struct User {
+    location: (f64, f64),
+}
+
+fn main() {}
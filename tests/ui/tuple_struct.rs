@@ -0,0 +1,6 @@
+use reflected::Reflected;
+
+#[derive(Reflected, Default)]
+struct Point(f64, f64);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use reflected::Reflected;
+
+#[derive(Reflected)]
+union Number {
+    int:   u32,
+    float: f32,
+}
+
+fn main() {}
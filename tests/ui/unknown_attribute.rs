@@ -0,0 +1,9 @@
+use reflected::Reflected;
+
+#[derive(Reflected, Default)]
+struct User {
+    #[reflected(primary)]
+    id: usize,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use reflected::Reflected;
+
+#[derive(Reflected, Default)]
+#[reflected(rename_all = "Title Case")]
+struct User {
+    id: usize,
+}
+
+fn main() {}
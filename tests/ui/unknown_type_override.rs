@@ -0,0 +1,11 @@
+use reflected::Reflected;
+
+type Tags = Vec<String>;
+
+#[derive(Reflected, Default)]
+struct User {
+    #[reflected(type = "Vec")]
+    tags: Tags,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use reflected::Reflected;
+
+#[derive(Reflected, Default)]
+struct User {
+    #[reflected(tag = 0)]
+    id: usize,
+}
+
+fn main() {}